@@ -0,0 +1,48 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+// counters are per thread, so solvers running side by side (like tests) don't
+// mix their numbers
+thread_local! {
+    static COUNTERS: RefCell<BTreeMap<&'static str, u64>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Adds `n` to the counter `name`.
+pub fn add(name: &'static str, n: u64) {
+    COUNTERS.with(|counters| *counters.borrow_mut().entry(name).or_insert(0) += n);
+}
+
+pub fn incr(name: &'static str) {
+    add(name, 1);
+}
+
+/// Raises the counter `name` to `value` if it is lower, for high-water marks
+/// like queue sizes.
+pub fn max(name: &'static str, value: u64) {
+    COUNTERS.with(|counters| {
+        let mut counters = counters.borrow_mut();
+        let counter = counters.entry(name).or_insert(0);
+        *counter = (*counter).max(value);
+    });
+}
+
+/// Returns every counter set since the last call, sorted by name, and resets
+/// them all.
+pub fn take() -> Vec<(&'static str, u64)> {
+    COUNTERS.with(|counters| counters.take().into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters() {
+        take();
+        incr("walk calls");
+        add("walk calls", 2);
+        max("max queue", 5);
+        max("max queue", 3);
+        assert_eq!(take(), vec![("max queue", 5), ("walk calls", 3)]);
+        assert_eq!(take(), vec![]);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod counters;
+pub mod parse;
+pub mod rng;
+pub mod search;
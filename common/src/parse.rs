@@ -0,0 +1,257 @@
+use std::{fmt, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, space0},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, tuple},
+    Finish, IResult, Parser,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+    pub text: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: unexpected input ({:?})",
+            self.line, self.column, self.kind
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A signed (or unsigned, depending on `T`) decimal integer: `42`, `-7`.
+pub fn int<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), |s: &str| {
+        s.parse::<T>()
+    })(input)
+}
+
+/// `key=value`, as in `rate=13`.
+pub fn assign<'a, O, F>(key: &'static str, value: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(pair(tag(key), char('=')), value)
+}
+
+/// An `x=..., y=...` coordinate pair, with or without a space after the comma.
+pub fn coord<T: FromStr>(input: &str) -> IResult<&str, (T, T)> {
+    separated_pair(assign("x", int), pair(char(','), space0), assign("y", int))(input)
+}
+
+/// One or more items separated by commas: `79, 98` or `1,2,3`.
+pub fn comma_list<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// A labelled value, `Label: value`, allowing for leading indentation.
+pub fn field<'a, O, F>(label: &'static str, value: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(tuple((space0, tag(label), char(':'), space0)), value)
+}
+
+/// Runs `parser` over the whole of `text`, which is line number `index + 1`
+/// of the input, and reports where it stopped if it fails.
+pub fn parse_line<'a, O, F>(parser: F, index: usize, text: &'a str) -> Result<O, ParseError>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    match all_consuming(parser)(text).finish() {
+        Ok((_, value)) => Ok(value),
+        Err(e) => {
+            let offset = text.len() - e.input.len();
+            Err(ParseError {
+                line: index + 1,
+                column: text[..offset].chars().count() + 1,
+                kind: e.code,
+                text: text.to_string(),
+            })
+        }
+    }
+}
+
+fn parse_lines_from<'a, O, F>(
+    mut parser: F,
+    start: usize,
+    lines: &'a [String],
+) -> Result<Vec<O>, ParseError>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    let mut result = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        result.push(parse_line(|input| parser.parse(input), start + i, line)?);
+    }
+    Ok(result)
+}
+
+/// Parses every line with the same `parser`.
+pub fn parse_lines<'a, O, F>(parser: F, lines: &'a [String]) -> Result<Vec<O>, ParseError>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    parse_lines_from(parser, 0, lines)
+}
+
+/// A run of non-blank lines. `start` is the index of its first line in the
+/// whole input, so errors point at the right place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    pub start: usize,
+    pub lines: &'a [String],
+}
+
+impl<'a> Block<'a> {
+    pub fn parse_line<O, F>(&self, index: usize, parser: F) -> Result<O, ParseError>
+    where
+        F: Parser<&'a str, O, Error<&'a str>>,
+    {
+        match self.lines.get(index) {
+            Some(line) => parse_line(parser, self.start + index, line),
+            None => Err(ParseError {
+                line: self.start + index + 1,
+                column: 1,
+                kind: ErrorKind::Eof,
+                text: String::new(),
+            }),
+        }
+    }
+
+    pub fn parse_lines<O, F>(&self, parser: F) -> Result<Vec<O>, ParseError>
+    where
+        F: Parser<&'a str, O, Error<&'a str>>,
+    {
+        parse_lines_from(parser, self.start, self.lines)
+    }
+}
+
+/// Splits the input into blocks separated by one or more blank lines.
+pub fn blocks(lines: &[String]) -> Vec<Block<'_>> {
+    blocks_by(lines, |_| false)
+}
+
+/// Like `blocks`, but a line `header` holds for also starts a new block, so
+/// the blank lines between blocks can be missing.
+pub fn blocks_by<F>(lines: &[String], header: F) -> Vec<Block<'_>>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut result = vec![];
+    let mut start = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let blank = line.trim().is_empty();
+        if blank || header(line) {
+            if i > start {
+                result.push(Block {
+                    start,
+                    lines: &lines[start..i],
+                });
+            }
+            start = if blank { i + 1 } else { i };
+        }
+    }
+    if lines.len() > start {
+        result.push(Block {
+            start,
+            lines: &lines[start..],
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::alpha1;
+
+    use super::*;
+
+    fn to_lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_int() {
+        assert_eq!(int::<i32>("-42 rest"), Ok((" rest", -42)));
+        assert_eq!(int::<u32>("17"), Ok(("", 17)));
+        assert!(int::<u32>("-17").is_err());
+        assert!(int::<i32>("x").is_err());
+    }
+
+    #[test]
+    fn test_coord() {
+        assert_eq!(coord::<i32>("x=2, y=-18"), Ok(("", (2, -18))));
+        assert_eq!(coord::<i32>("x=-2,y=15:"), Ok((":", (-2, 15))));
+    }
+
+    #[test]
+    fn test_comma_list() {
+        assert_eq!(comma_list(int::<u32>)("79, 98"), Ok(("", vec![79, 98])));
+        assert_eq!(comma_list(int::<u32>)("1,2,3"), Ok(("", vec![1, 2, 3])));
+        assert_eq!(
+            comma_list(alpha1)("DD, II, BB"),
+            Ok(("", vec!["DD", "II", "BB"]))
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        let mut items = field("Starting items", comma_list(int::<u32>));
+        assert_eq!(items("  Starting items: 79, 98"), Ok(("", vec![79, 98])));
+        assert_eq!(assign("rate", int::<u32>)("rate=13;"), Ok((";", 13)));
+    }
+
+    #[test]
+    fn test_parse_line_error() {
+        let error = parse_line(coord::<i32>, 2, "x=2, y=ab").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 8);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 8: unexpected input (Digit)\n  x=2, y=ab\n         ^"
+        );
+
+        let error = parse_line(int::<i32>, 0, "12 ").unwrap_err();
+        assert_eq!((error.column, error.kind), (3, ErrorKind::Eof));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let lines = to_lines(&["1", "2", "", "", "3", "x", ""]);
+        let blocks = blocks(&lines);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].parse_lines(int::<u32>), Ok(vec![1, 2]));
+        assert_eq!(blocks[1].start, 4);
+        assert_eq!(blocks[1].parse_line(0, int::<u32>), Ok(3));
+        assert_eq!(blocks[1].parse_lines(int::<u32>).unwrap_err().line, 6);
+        assert_eq!(blocks[1].parse_line(2, int::<u32>).unwrap_err().line, 7);
+    }
+
+    #[test]
+    fn test_blocks_by() {
+        let header = |line: &str| line.starts_with('#');
+        let lines = to_lines(&["#1", "a", "#2", "b", "", "#3", "", "c"]);
+        let blocks: Vec<(usize, usize)> = blocks_by(&lines, header)
+            .iter()
+            .map(|block| (block.start, block.lines.len()))
+            .collect();
+        assert_eq!(blocks, [(0, 2), (2, 2), (5, 1), (7, 1)]);
+    }
+}
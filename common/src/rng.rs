@@ -0,0 +1,73 @@
+/// A small seeded generator (SplitMix64), so simulations and generated inputs
+/// come out the same for the same seed on every machine.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        // multiply and shift instead of `%`, which favours small numbers
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in `from..=to`.
+    pub fn between(&mut self, from: i64, to: i64) -> i64 {
+        from + self.below((to - from) as u64 + 1) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let xs: Vec<u64> = (0..10).map(|_| a.below(6)).collect();
+        let ys: Vec<u64> = (0..10).map(|_| b.below(6)).collect();
+        assert_eq!(xs, ys);
+        assert!(xs.iter().all(|&x| x < 6));
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+
+        let mut counts = [0; 3];
+        for _ in 0..30_000 {
+            counts[(a.between(-1, 1) + 1) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (9_000..11_000).contains(&c)));
+
+        let mut items: Vec<usize> = (0..20).collect();
+        a.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
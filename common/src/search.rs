@@ -0,0 +1,423 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Counters filled in by every search, so solutions can report how much work
+/// they did.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub expanded: usize,
+    pub max_frontier: usize,
+    pub duplicates: usize,
+    pub pruned: usize,
+}
+
+impl Stats {
+    fn frontier(&mut self, len: usize) {
+        self.max_frontier = self.max_frontier.max(len);
+    }
+}
+
+/// Decides which states count as already seen. BFS checks states as they are
+/// queued, Dijkstra and A* as they are expanded.
+pub trait Dedup<S> {
+    /// Returns `false` if an equivalent state was seen before.
+    fn insert(&mut self, state: &S) -> bool;
+}
+
+/// Never drops a state. Only safe when the state space has no cycles.
+pub struct NoDedup;
+
+impl<S> Dedup<S> for NoDedup {
+    fn insert(&mut self, _: &S) -> bool {
+        true
+    }
+}
+
+impl<S: Hash + Eq + Clone> Dedup<S> for HashSet<S> {
+    fn insert(&mut self, state: &S) -> bool {
+        HashSet::insert(self, state.clone())
+    }
+}
+
+/// Treats states with the same key as equivalent, e.g. a position and the
+/// time modulo some cycle.
+pub struct DedupBy<K, F> {
+    pub seen: HashSet<K>,
+    key: F,
+}
+
+impl<K, F> DedupBy<K, F> {
+    pub fn new(key: F) -> Self {
+        Self {
+            seen: HashSet::new(),
+            key,
+        }
+    }
+}
+
+impl<S, K: Hash + Eq, F: Fn(&S) -> K> Dedup<S> for DedupBy<K, F> {
+    fn insert(&mut self, state: &S) -> bool {
+        self.seen.insert((self.key)(state))
+    }
+}
+
+// every state reached is stored once, with the index of the state it was
+// reached from, so the path can be walked back from the goal
+struct Arena<S> {
+    nodes: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone> Arena<S> {
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((state, parent));
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![];
+        loop {
+            let (state, parent) = &self.nodes[index];
+            path.push(state.clone());
+            match parent {
+                Some(parent) => index = *parent,
+                None => break,
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search. Returns the states from `start` to the first state
+/// that satisfies `success`, both included.
+pub fn bfs<S, I, FN, FS, D>(
+    start: S,
+    mut successors: FN,
+    mut success: FS,
+    dedup: &mut D,
+    stats: &mut Stats,
+) -> Option<Vec<S>>
+where
+    S: Clone,
+    I: IntoIterator<Item = S>,
+    FN: FnMut(&S) -> I,
+    FS: FnMut(&S) -> bool,
+    D: Dedup<S>,
+{
+    let mut arena = Arena { nodes: vec![] };
+    let mut queue = VecDeque::new();
+    dedup.insert(&start);
+    queue.push_back(arena.push(start, None));
+    stats.frontier(queue.len());
+
+    while let Some(index) = queue.pop_front() {
+        let state = arena.nodes[index].0.clone();
+        if success(&state) {
+            return Some(arena.path(index));
+        }
+        stats.expanded += 1;
+        for next in successors(&state) {
+            if !dedup.insert(&next) {
+                stats.duplicates += 1;
+                continue;
+            }
+            queue.push_back(arena.push(next, Some(index)));
+        }
+        stats.frontier(queue.len());
+    }
+    None
+}
+
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// reversed, so the binary heap pops the lowest estimate first, and among
+// equal estimates the one that got furthest
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost, and
+/// `C::default()` is taken as zero. Returns the path and its total cost.
+pub fn astar<S, C, I, FN, FH, FS, D>(
+    start: S,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+    dedup: &mut D,
+    stats: &mut Stats,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    FN: FnMut(&S) -> I,
+    FH: FnMut(&S) -> C,
+    FS: FnMut(&S) -> bool,
+    D: Dedup<S>,
+{
+    let mut arena = Arena { nodes: vec![] };
+    let mut heap = BinaryHeap::new();
+    heap.push(Entry {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: arena.push(start, None),
+    });
+    stats.frontier(heap.len());
+
+    while let Some(Entry { cost, index, .. }) = heap.pop() {
+        let state = arena.nodes[index].0.clone();
+        if !dedup.insert(&state) {
+            stats.duplicates += 1;
+            continue;
+        }
+        if success(&state) {
+            return Some((arena.path(index), cost));
+        }
+        stats.expanded += 1;
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            heap.push(Entry {
+                estimate: cost + heuristic(&next),
+                cost,
+                index: arena.push(next, Some(index)),
+            });
+        }
+        stats.frontier(heap.len());
+    }
+    None
+}
+
+/// Dijkstra's algorithm, which is A* without a heuristic.
+pub fn dijkstra<S, C, I, FN, FS, D>(
+    start: S,
+    successors: FN,
+    success: FS,
+    dedup: &mut D,
+    stats: &mut Stats,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    FN: FnMut(&S) -> I,
+    FS: FnMut(&S) -> bool,
+    D: Dedup<S>,
+{
+    astar(start, successors, |_| C::default(), success, dedup, stats)
+}
+
+struct BranchAndBound<'a, S, V, FN, FV, FB> {
+    successors: FN,
+    value: FV,
+    bound: FB,
+    path: Vec<S>,
+    best: Option<(Vec<S>, V)>,
+    stats: &'a mut Stats,
+}
+
+impl<'a, S, V, I, FN, FV, FB> BranchAndBound<'a, S, V, FN, FV, FB>
+where
+    S: Clone,
+    V: Copy + Ord,
+    I: IntoIterator<Item = S>,
+    FN: FnMut(&S) -> I,
+    FV: FnMut(&S) -> V,
+    FB: FnMut(&S) -> V,
+{
+    fn visit(&mut self, state: S) {
+        if let Some((_, best)) = self.best {
+            if (self.bound)(&state) <= best {
+                // this branch won't beat the record
+                self.stats.pruned += 1;
+                return;
+            }
+        }
+        self.stats.expanded += 1;
+        self.path.push(state.clone());
+        self.stats.frontier(self.path.len());
+
+        let value = (self.value)(&state);
+        let better = match &self.best {
+            Some((_, best)) => value > *best,
+            None => true,
+        };
+        if better {
+            self.best = Some((self.path.clone(), value));
+        }
+        for next in (self.successors)(&state) {
+            self.visit(next);
+        }
+        self.path.pop();
+    }
+}
+
+/// Depth-first search for the state with the highest `value`. `bound` gives
+/// an upper bound on the value of anything reachable from a state, and
+/// branches that can't beat the best value found so far are skipped.
+/// Returns the path to the best state and its value.
+pub fn branch_and_bound<S, V, I, FN, FV, FB>(
+    start: S,
+    successors: FN,
+    value: FV,
+    bound: FB,
+    stats: &mut Stats,
+) -> (Vec<S>, V)
+where
+    S: Clone,
+    V: Copy + Ord,
+    I: IntoIterator<Item = S>,
+    FN: FnMut(&S) -> I,
+    FV: FnMut(&S) -> V,
+    FB: FnMut(&S) -> V,
+{
+    let mut search = BranchAndBound {
+        successors,
+        value,
+        bound,
+        path: vec![],
+        best: None,
+        stats,
+    };
+    search.visit(start);
+    search.best.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: [&str; 4] = ["S..#", ".#..", ".#.#", "...E"];
+
+    fn neighbors(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        let mut result = vec![];
+        for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+            let (nx, ny) = (x + dx, y + dy);
+            if nx < 0 || ny < 0 || nx >= 4 || ny >= 4 {
+                continue;
+            }
+            if GRID[ny as usize].as_bytes()[nx as usize] != b'#' {
+                result.push((nx, ny));
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_bfs() {
+        let mut stats = Stats::default();
+        let path = bfs(
+            (0, 0),
+            neighbors,
+            |&p| p == (3, 3),
+            &mut HashSet::new(),
+            &mut stats,
+        );
+        let path = path.unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[6], (3, 3));
+        assert!(stats.expanded > 0 && stats.max_frontier > 0);
+
+        let mut seen = HashSet::new();
+        let path = bfs((0, 0), neighbors, |_| false, &mut seen, &mut stats);
+        assert_eq!(path, None);
+        assert_eq!(seen.len(), 12);
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        // walking a 5-cycle, states are (node, time) and only node matters
+        let successors = |&(node, time): &(u32, u32)| vec![((node + 1) % 5, time + 1)];
+        let mut dedup = DedupBy::new(|&(node, _): &(u32, u32)| node);
+        let path = bfs(
+            (0, 0),
+            successors,
+            |_| false,
+            &mut dedup,
+            &mut Stats::default(),
+        );
+        assert_eq!(path, None);
+        assert_eq!(dedup.seen.len(), 5);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // going 0 -> 1 -> 2 -> 3 is cheaper than the direct edges
+        let edges = |&n: &u32| -> Vec<(u32, u32)> {
+            match n {
+                0 => vec![(1, 1), (3, 10)],
+                1 => vec![(2, 1), (3, 7)],
+                2 => vec![(3, 1)],
+                _ => vec![],
+            }
+        };
+        let mut stats = Stats::default();
+        let result = dijkstra(0, edges, |&n| n == 3, &mut HashSet::new(), &mut stats);
+        assert_eq!(result, Some((vec![0, 1, 2, 3], 3)));
+
+        let result = astar(
+            0,
+            edges,
+            |&n| 3 - n,
+            |&n| n == 3,
+            &mut HashSet::new(),
+            &mut Stats::default(),
+        );
+        assert_eq!(result, Some((vec![0, 1, 2, 3], 3)));
+
+        let result = dijkstra(3, edges, |&n| n == 0, &mut NoDedup, &mut stats);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_branch_and_bound() {
+        // 0/1 knapsack with capacity 10: states are (next item, weight, value)
+        let items = [(5, 10), (4, 40), (6, 30), (3, 50)];
+        let successors = |&(i, weight, value): &(usize, u32, u32)| {
+            let mut result = vec![];
+            if i < items.len() {
+                let (w, v) = items[i];
+                if weight + w <= 10 {
+                    result.push((i + 1, weight + w, value + v));
+                }
+                result.push((i + 1, weight, value));
+            }
+            result
+        };
+        let bound = |&(i, _, value): &(usize, u32, u32)| {
+            value + items[i..].iter().map(|(_, v)| v).sum::<u32>()
+        };
+        let mut stats = Stats::default();
+        let (path, value) = branch_and_bound((0, 0, 0), successors, |s| s.2, bound, &mut stats);
+        assert_eq!(value, 90);
+        assert_eq!(path.last(), Some(&(4, 7, 90)));
+        assert!(stats.pruned > 0);
+    }
+}
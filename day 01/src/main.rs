@@ -0,0 +1,65 @@
+use std::{
+    env,
+    io::{self, BufRead},
+    process,
+};
+
+use day01::{report, top::top_k_from};
+
+const HISTOGRAM_BUCKETS: usize = 10;
+
+fn fail(error: impl std::fmt::Display) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
+}
+
+fn usage() -> ! {
+    eprintln!("usage: day01 [k] < input.txt");
+    eprintln!("       day01 --report [text|csv|json] < input.txt");
+    process::exit(2);
+}
+
+// `day01 < input.txt` prints both parts, `day01 <k> < input.txt` the total
+// of the top `k` elves and who they are
+fn top(k: Option<usize>) {
+    let ranking = top_k_from(io::stdin().lock(), k.unwrap_or(3)).unwrap_or_else(|e| fail(e));
+    match k {
+        None => {
+            println!("{}", ranking.elves.first().map_or(0, |elf| elf.calories));
+            println!("{}", ranking.sum);
+        }
+        Some(k) => {
+            println!("top {}: {}", k, ranking.sum);
+            for (rank, elf) in ranking.elves.iter().enumerate() {
+                println!("{}. elf {}: {}", rank + 1, elf.index, elf.calories);
+            }
+        }
+    }
+}
+
+fn print_report(format: &str) {
+    let lines = io::stdin().lock().lines();
+    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
+    let report = report::report(&lines, HISTOGRAM_BUCKETS).unwrap_or_else(|e| fail(e));
+    let output = match format {
+        "text" => report::render_text(&report),
+        "csv" => report::render_csv(&report),
+        "json" => report::render_json(&report),
+        _ => usage(),
+    };
+    for line in output {
+        println!("{}", line);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    match args[..] {
+        [] => top(None),
+        ["--report"] => print_report("text"),
+        ["--report", format] => print_report(format),
+        [k] => top(Some(k.parse().unwrap_or_else(|_| usage()))),
+        _ => usage(),
+    }
+}
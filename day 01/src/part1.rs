@@ -0,0 +1,39 @@
+use crate::top::{top_k, top_k_of, totals, Elf};
+
+pub const K: usize = 1;
+
+pub fn parse_input(lines: Vec<String>) -> Vec<i32> {
+    totals(lines)
+        .map(|elf| elf.unwrap_or_else(|e| panic!("{}", e)).calories)
+        .collect()
+}
+
+pub fn solution(calories: Vec<i32>) -> i32 {
+    let elves = calories
+        .into_iter()
+        .enumerate()
+        .map(|(index, calories)| Elf { index, calories });
+    top_k_of(elves, K).sum
+}
+
+pub fn solve(lines: &[String]) -> i32 {
+    top_k(lines, K).unwrap_or_else(|e| panic!("{}", e)).sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let lines = ["1000", "2000", "", "3000", "4000"];
+        let calories = parse_input(lines.iter().map(|x| x.to_string()).collect());
+        assert_eq!(calories, [3000, 7000]);
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(solution(vec![10, 20, 30]), 30);
+        assert_eq!(solution(vec![]), 0);
+    }
+}
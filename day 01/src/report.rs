@@ -0,0 +1,261 @@
+use common::parse::ParseError;
+
+use crate::top;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub index: usize,
+    pub items: usize,
+    pub total: i32,
+    pub mean: f64,
+    pub max: i32,
+    // 1 for the elf carrying the most, ties ranked by position
+    pub rank: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: i32,
+    pub to: i32,
+    pub count: usize,
+}
+
+pub struct Report {
+    pub rows: Vec<Row>,
+    pub histogram: Vec<Bucket>,
+}
+
+pub fn inventories(lines: &[String]) -> Result<Vec<Vec<i32>>, ParseError> {
+    top::inventories(lines).collect()
+}
+
+pub fn rows(inventories: &[Vec<i32>]) -> Vec<Row> {
+    let mut rows: Vec<Row> = inventories
+        .iter()
+        .enumerate()
+        .map(|(index, items)| {
+            let total = items.iter().sum();
+            Row {
+                index,
+                items: items.len(),
+                total,
+                mean: total as f64 / items.len() as f64,
+                max: items.iter().copied().max().unwrap_or_default(),
+                rank: 0,
+            }
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    order.sort_by_key(|&i| (-rows[i].total, i));
+    for (rank, i) in order.into_iter().enumerate() {
+        rows[i].rank = rank + 1;
+    }
+    rows
+}
+
+/// Splits the range of totals into `buckets` equally wide buckets (the last
+/// ones may be left out if the range is small) and counts the elves in each.
+pub fn histogram(rows: &[Row], buckets: usize) -> Vec<Bucket> {
+    let (Some(min), Some(max)) = (
+        rows.iter().map(|row| row.total).min(),
+        rows.iter().map(|row| row.total).max(),
+    ) else {
+        return vec![];
+    };
+    let buckets = buckets.max(1) as i32;
+    let width = ((max - min) / buckets + 1).max(1);
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|i| Bucket {
+            from: min + i * width,
+            to: min + (i + 1) * width - 1,
+            count: 0,
+        })
+        .take_while(|bucket| bucket.from <= max)
+        .collect();
+    for row in rows {
+        histogram[((row.total - min) / width) as usize].count += 1;
+    }
+    histogram
+}
+
+pub fn report(lines: &[String], buckets: usize) -> Result<Report, ParseError> {
+    let rows = rows(&inventories(lines)?);
+    let histogram = histogram(&rows, buckets);
+    Ok(Report { rows, histogram })
+}
+
+const BAR_WIDTH: usize = 50;
+
+pub fn render_text(report: &Report) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{:>5} {:>5} {:>8} {:>10} {:>8} {:>5}",
+        "elf", "items", "total", "mean", "max", "rank"
+    )];
+    for row in report.rows.iter() {
+        lines.push(format!(
+            "{:>5} {:>5} {:>8} {:>10.2} {:>8} {:>5}",
+            row.index, row.items, row.total, row.mean, row.max, row.rank
+        ));
+    }
+
+    lines.push(String::new());
+    let most = report.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+    for bucket in report.histogram.iter() {
+        let bar = (bucket.count * BAR_WIDTH + most - 1) / most.max(1);
+        lines.push(
+            format!(
+                "{:>8}-{:<8} {:>5} {}",
+                bucket.from,
+                bucket.to,
+                bucket.count,
+                "#".repeat(bar)
+            )
+            .trim_end()
+            .to_string(),
+        );
+    }
+    lines
+}
+
+pub fn render_csv(report: &Report) -> Vec<String> {
+    let mut lines = vec!["elf,items,total,mean,max,rank".to_string()];
+    for row in report.rows.iter() {
+        lines.push(format!(
+            "{},{},{},{:.2},{},{}",
+            row.index, row.items, row.total, row.mean, row.max, row.rank
+        ));
+    }
+    lines
+}
+
+// one object per line, with commas between them
+fn json_array(name: &str, objects: Vec<String>, last: bool) -> Vec<String> {
+    let mut lines = vec![format!("  \"{}\": [", name)];
+    let count = objects.len();
+    for (i, object) in objects.into_iter().enumerate() {
+        let comma = if i + 1 < count { "," } else { "" };
+        lines.push(format!("    {}{}", object, comma));
+    }
+    lines.push(format!("  ]{}", if last { "" } else { "," }));
+    lines
+}
+
+pub fn render_json(report: &Report) -> Vec<String> {
+    let rows = report
+        .rows
+        .iter()
+        .map(|row| {
+            format!(
+                "{{\"elf\": {}, \"items\": {}, \"total\": {}, \"mean\": {:.2}, \"max\": {}, \"rank\": {}}}",
+                row.index, row.items, row.total, row.mean, row.max, row.rank
+            )
+        })
+        .collect();
+    let buckets = report
+        .histogram
+        .iter()
+        .map(|bucket| {
+            format!(
+                "{{\"from\": {}, \"to\": {}, \"count\": {}}}",
+                bucket.from, bucket.to, bucket.count
+            )
+        })
+        .collect();
+
+    let mut lines = vec!["{".to_string()];
+    lines.extend(json_array("elves", rows, false));
+    lines.extend(json_array("histogram", buckets, true));
+    lines.push("}".to_string());
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<String> {
+        let lines = [
+            "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
+            "10000",
+        ];
+        lines.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_rows() {
+        let rows = rows(&inventories(&example()).unwrap());
+        assert_eq!(rows.len(), 5);
+        assert_eq!(
+            rows[0],
+            Row {
+                index: 0,
+                items: 3,
+                total: 6000,
+                mean: 2000.0,
+                max: 3000,
+                rank: 4
+            }
+        );
+        let ranks: Vec<usize> = rows.iter().map(|row| row.rank).collect();
+        assert_eq!(ranks, vec![4, 5, 2, 1, 3]);
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        // read the same way as for the ranking, spaces and all
+        let lines: Vec<String> = ["", " 1000", "2000 ", "  ", "", "3000"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(
+            inventories(&lines).unwrap(),
+            vec![vec![1000, 2000], vec![3000]]
+        );
+        let totals: Vec<i32> = top::totals(&lines)
+            .map(|elf| elf.unwrap().calories)
+            .collect();
+        assert_eq!(totals, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let rows = rows(&inventories(&example()).unwrap());
+        // totals are 6000, 4000, 11000, 24000 and 10000
+        let histogram = histogram(&rows, 4);
+        let counts: Vec<(i32, usize)> = histogram.iter().map(|b| (b.from, b.count)).collect();
+        assert_eq!(counts, vec![(4000, 2), (9001, 2), (14002, 0), (19003, 1)]);
+        assert_eq!(histogram[3].to, 24003);
+        assert_eq!(super::histogram(&[], 4), vec![]);
+
+        // all the same total, a single bucket
+        let rows = super::rows(&[vec![1], vec![1]]);
+        assert_eq!(
+            super::histogram(&rows, 10),
+            vec![Bucket {
+                from: 1,
+                to: 1,
+                count: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let report = report(&example(), 2).unwrap();
+        let csv = render_csv(&report);
+        assert_eq!(csv[0], "elf,items,total,mean,max,rank");
+        assert_eq!(csv[4], "3,3,24000,8000.00,9000,1");
+
+        let json = render_json(&report).join("\n");
+        assert!(json.starts_with("{\n  \"elves\": [\n"));
+        assert!(json.contains(
+            "{\"elf\": 1, \"items\": 1, \"total\": 4000, \"mean\": 4000.00, \"max\": 4000, \"rank\": 5},\n"
+        ));
+        assert!(json.ends_with("{\"from\": 14001, \"to\": 24001, \"count\": 1}\n  ]\n}"));
+
+        let text = render_text(&report);
+        assert_eq!(text.len(), 1 + 5 + 1 + 2);
+        assert!(text[7].ends_with(&"#".repeat(BAR_WIDTH)));
+    }
+}
@@ -0,0 +1,197 @@
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use common::parse::{int, parse_line, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    // position of the elf in the input, starting at 0
+    pub index: usize,
+    pub calories: i32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Ranking {
+    pub sum: i32,
+    // largest total first, ties go to the elf that came first
+    pub elves: Vec<Elf>,
+}
+
+/// Turns lines into one inventory per elf as it goes, without holding on to
+/// anything but the elf being read. Blank lines (spaces and all) separate
+/// elves, however many there are. A line that isn't a number is an error in
+/// place of the elf it's in.
+pub struct Inventories<I> {
+    lines: I,
+    line: usize,
+}
+
+pub fn inventories<I: IntoIterator>(lines: I) -> Inventories<I::IntoIter> {
+    Inventories {
+        lines: lines.into_iter(),
+        line: 0,
+    }
+}
+
+impl<I, S> Iterator for Inventories<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    type Item = Result<Vec<i32>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = vec![];
+        for line in self.lines.by_ref() {
+            let index = self.line;
+            self.line += 1;
+            let line = line.as_ref().trim();
+            if line.is_empty() {
+                if !items.is_empty() {
+                    break;
+                }
+                continue;
+            }
+            match parse_line(int::<i32>, index, line) {
+                Ok(item) => items.push(item),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+        if items.is_empty() {
+            None
+        } else {
+            Some(Ok(items))
+        }
+    }
+}
+
+/// One total per elf.
+pub fn totals<I, S>(lines: I) -> impl Iterator<Item = Result<Elf, ParseError>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    inventories(lines).enumerate().map(|(index, items)| {
+        Ok(Elf {
+            index,
+            calories: items?.iter().sum(),
+        })
+    })
+}
+
+/// Keeps the `k` largest totals seen so far in a min-heap, so the smallest
+/// of them is the one to drop when a bigger one comes along.
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<(i32, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: Elf) {
+        self.heap.push(Reverse((elf.calories, Reverse(elf.index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    pub fn ranking(self) -> Ranking {
+        let elves: Vec<Elf> = self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect();
+        Ranking {
+            sum: elves.iter().map(|elf| elf.calories).sum(),
+            elves,
+        }
+    }
+}
+
+/// The `k` largest of `elves`.
+pub fn top_k_of<I: IntoIterator<Item = Elf>>(elves: I, k: usize) -> Ranking {
+    let mut top = TopK::new(k);
+    for elf in elves {
+        top.push(elf);
+    }
+    top.ranking()
+}
+
+/// The `k` largest totals, unless a line isn't a number.
+pub fn top_k<I, S>(lines: I, k: usize) -> Result<Ranking, ParseError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut top = TopK::new(k);
+    for elf in totals(lines) {
+        top.push(elf?);
+    }
+    Ok(top.ranking())
+}
+
+pub fn top_k_from<R: BufRead>(reader: R, k: usize) -> Result<Ranking, ParseError> {
+    top_k(reader.lines().map(|line| line.unwrap()), k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    fn elf(index: usize, calories: i32) -> Elf {
+        Elf { index, calories }
+    }
+
+    #[test]
+    fn test_totals() {
+        let lines = ["", "1000", "2000", "", "", "3000", " 4000 ", ""];
+        let elves: Vec<Elf> = totals(lines).collect::<Result<_, _>>().unwrap();
+        assert_eq!(elves, vec![elf(0, 3000), elf(1, 7000)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = top_k(["1000", "", "20x0", "5"], 3).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        let mut elves = totals(["1000", "", "x"]);
+        assert_eq!(elves.next(), Some(Ok(elf(0, 1000))));
+        assert_eq!(elves.next().unwrap().unwrap_err().line, 3);
+    }
+
+    #[test]
+    fn test_top_k() {
+        let ranking = top_k_from(EXAMPLE.as_bytes(), 3).unwrap();
+        assert_eq!(ranking.sum, 45000);
+        assert_eq!(
+            ranking.elves,
+            vec![elf(3, 24000), elf(2, 11000), elf(4, 10000)]
+        );
+
+        assert_eq!(top_k_from(EXAMPLE.as_bytes(), 1).unwrap().sum, 24000);
+        assert_eq!(top_k_from(EXAMPLE.as_bytes(), 10).unwrap().elves.len(), 5);
+        assert_eq!(top_k_from(EXAMPLE.as_bytes(), 0).unwrap().sum, 0);
+    }
+
+    #[test]
+    fn test_many_elves() {
+        // generated on the fly, so nothing but the heap is kept around
+        let n = 500_000;
+        let lines = (0..n).flat_map(|i| [(i % 1000).to_string(), String::new()]);
+        let ranking = top_k(lines, 2).unwrap();
+        assert_eq!(ranking.elves, vec![elf(999, 999), elf(1999, 999)]);
+    }
+
+    #[test]
+    fn test_ties() {
+        let ranking = top_k(["5", "", "7", "", "5", "", "5"], 3).unwrap();
+        assert_eq!(ranking.elves, vec![elf(1, 7), elf(0, 5), elf(2, 5)]);
+    }
+}
@@ -0,0 +1,207 @@
+/// A move, as an index into the moves of a `Game`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Play(pub usize);
+
+// the moves of ROCK_PAPER_SCISSORS
+pub const ROCK: Play = Play(0);
+pub const PAPER: Play = Play(1);
+pub const SCISSORS: Play = Play(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> i32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub elf: Play,
+    pub me: Play,
+}
+
+/// A cyclic game with an odd number of moves, where every move beats the
+/// half of the other moves that come right before it in the cycle (wrapping
+/// around) and loses to the half that come right after it. Each move has a
+/// name and the score for picking it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub moves: &'static [(&'static str, i32)],
+}
+
+impl Game {
+    pub const fn new(moves: &'static [(&'static str, i32)]) -> Self {
+        assert!(
+            moves.len() % 2 == 1,
+            "a cyclic game needs an odd number of moves"
+        );
+        Self { moves }
+    }
+
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    pub fn plays(&self) -> impl Iterator<Item = Play> {
+        (0..self.len()).map(Play)
+    }
+
+    pub fn name(&self, play: Play) -> &'static str {
+        self.moves[play.0].0
+    }
+
+    pub fn outcome(&self, elf: Play, me: Play) -> Outcome {
+        let n = self.len();
+        match (me.0 + n - elf.0) % n {
+            0 => Outcome::Draw,
+            d if d <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// The move that gets `outcome` against `elf`. When there are several
+    /// (games with more than three moves), it's the nearest one in the cycle.
+    pub fn response(&self, elf: Play, outcome: Outcome) -> Play {
+        let n = self.len();
+        match outcome {
+            Outcome::Loss => Play((elf.0 + n - 1) % n),
+            Outcome::Draw => elf,
+            Outcome::Win => Play((elf.0 + 1) % n),
+        }
+    }
+
+    pub fn score(&self, round: &Round) -> i32 {
+        self.moves[round.me.0].1 + self.outcome(round.elf, round.me).score()
+    }
+
+    /// The elf's column of the strategy guide uses `A`, `B`, `C`, ...
+    pub fn map_elf(&self, s: &str) -> Play {
+        self.map_letter(s, b'A')
+    }
+
+    /// My column uses the last letters of the alphabet, `X`, `Y` and `Z` for
+    /// three moves and `V` to `Z` for five.
+    pub fn map_me(&self, s: &str) -> Play {
+        self.map_letter(s, b'Z' + 1 - self.len() as u8)
+    }
+
+    fn map_letter(&self, s: &str, first: u8) -> Play {
+        match s.as_bytes() {
+            [c] if (first..first + self.len() as u8).contains(c) => Play((c - first) as usize),
+            _ => panic!("not a move: {}", s),
+        }
+    }
+}
+
+pub const ROCK_PAPER_SCISSORS: Game = Game::new(&[("Rock", 1), ("Paper", 2), ("Scissors", 3)]);
+
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: Game = Game::new(&[
+    ("Rock", 1),
+    ("Spock", 5),
+    ("Paper", 2),
+    ("Lizard", 4),
+    ("Scissors", 3),
+]);
+
+pub const ROCK_PAPER_SCISSORS_7: Game = Game::new(&[
+    ("Water", 7),
+    ("Air", 6),
+    ("Paper", 5),
+    ("Sponge", 4),
+    ("Scissors", 3),
+    ("Fire", 2),
+    ("Rock", 1),
+]);
+
+pub fn by_name(name: &str) -> Option<&'static Game> {
+    match name {
+        "rps" => Some(&ROCK_PAPER_SCISSORS),
+        "rpsls" => Some(&ROCK_PAPER_SCISSORS_LIZARD_SPOCK),
+        "rps7" => Some(&ROCK_PAPER_SCISSORS_7),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(game: &Game, name: &str) -> Play {
+        game.plays().find(|&p| game.name(p) == name).unwrap()
+    }
+
+    fn beats(game: &Game, a: &str, b: &str) -> bool {
+        game.outcome(play(game, b), play(game, a)) == Outcome::Win
+    }
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let game = &ROCK_PAPER_SCISSORS;
+        assert!(beats(game, "Rock", "Scissors"));
+        assert!(beats(game, "Scissors", "Paper"));
+        assert!(beats(game, "Paper", "Rock"));
+        assert_eq!(game.outcome(ROCK, ROCK), Outcome::Draw);
+        assert_eq!(game.response(ROCK, Outcome::Win), PAPER);
+        assert_eq!(game.response(ROCK, Outcome::Loss), SCISSORS);
+        assert_eq!(game.map_elf("C"), SCISSORS);
+        assert_eq!(game.map_me("Y"), PAPER);
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let game = &ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+        for (a, b) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert!(beats(game, a, b), "{} beats {}", a, b);
+            assert!(!beats(game, b, a), "{} loses to {}", b, a);
+        }
+        assert_eq!(game.map_me("V"), Play(0));
+    }
+
+    #[test]
+    fn test_balanced() {
+        // every move beats exactly as many moves as it loses to
+        for game in [
+            &ROCK_PAPER_SCISSORS,
+            &ROCK_PAPER_SCISSORS_LIZARD_SPOCK,
+            &ROCK_PAPER_SCISSORS_7,
+        ] {
+            for me in game.plays() {
+                let wins = game
+                    .plays()
+                    .filter(|&elf| game.outcome(elf, me) == Outcome::Win)
+                    .count();
+                assert_eq!(wins, game.len() / 2);
+                for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+                    assert_eq!(game.outcome(me, game.response(me, outcome)), outcome);
+                }
+            }
+        }
+        assert!(beats(&ROCK_PAPER_SCISSORS_7, "Rock", "Sponge"));
+        assert!(beats(&ROCK_PAPER_SCISSORS_7, "Water", "Rock"));
+    }
+}
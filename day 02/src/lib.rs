@@ -0,0 +1,5 @@
+pub mod game;
+pub mod part1;
+pub mod part2;
+pub mod readings;
+pub mod tournament;
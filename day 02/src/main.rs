@@ -0,0 +1,82 @@
+use std::{
+    env,
+    io::{self, BufRead},
+    process,
+};
+
+use day02::{
+    game::{self, Game},
+    part1, part2, readings,
+    tournament::{self, Entrant, FrequencyCounter, Guide, Random, WinStayLoseShift},
+};
+
+const MATCHES: usize = 100;
+const SEED: u64 = 2022;
+
+fn usage() -> ! {
+    eprintln!("usage: day02 [rps|rpsls|rps7] < input.txt");
+    eprintln!("       day02 --readings < input.txt");
+    eprintln!("       day02 --tournament <rounds> [seed] < input.txt");
+    process::exit(2);
+}
+
+// `day02 < input.txt` plays rock paper scissors, `day02 rpsls < input.txt`
+// scores the same kind of guide for another game
+fn play(game: &Game, lines: &[String]) {
+    println!("{}", part1::score(game, &part1::parse_rounds(game, lines)));
+    match part2::try_parse_rounds(game, lines) {
+        Some(rounds) => println!("{}", part2::score(game, &rounds)),
+        None => println!("-"),
+    }
+}
+
+// the guide from stdin (read as moves) against the other strategies
+fn play_tournament(lines: &[String], rounds: usize, seed: u64) {
+    let moves = part1::parse_input(lines.to_vec());
+    let guide = Guide::from_rounds(&moves).unwrap_or_else(|| {
+        eprintln!("the guide has no moves");
+        process::exit(1);
+    });
+    let entrants: Vec<Entrant> = vec![
+        Box::new(|| Box::new(guide.clone())),
+        Box::new(|| Box::new(Random)),
+        Box::new(|| Box::new(FrequencyCounter::default())),
+        Box::new(|| Box::new(WinStayLoseShift::default())),
+    ];
+    let game = &game::ROCK_PAPER_SCISSORS;
+    let distributions = tournament::tournament(game, &entrants, rounds, MATCHES, seed);
+    for line in tournament::render(&distributions) {
+        println!("{}", line);
+    }
+}
+
+enum Mode {
+    Play(&'static Game),
+    Readings,
+    Tournament(usize, u64),
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    let number = |arg: &str| arg.parse().unwrap_or_else(|_| usage());
+    let mode = match args[..] {
+        [] => Mode::Play(&game::ROCK_PAPER_SCISSORS),
+        ["--readings"] => Mode::Readings,
+        ["--tournament", rounds] => Mode::Tournament(number(rounds) as usize, SEED),
+        ["--tournament", rounds, seed] => Mode::Tournament(number(rounds) as usize, number(seed)),
+        [name] => Mode::Play(game::by_name(name).unwrap_or_else(|| usage())),
+        _ => usage(),
+    };
+    let lines = io::stdin().lock().lines();
+    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
+    match mode {
+        Mode::Play(game) => play(game, &lines),
+        Mode::Readings => {
+            for line in readings::render(&readings::score_all(&lines)) {
+                println!("{}", line);
+            }
+        }
+        Mode::Tournament(rounds, seed) => play_tournament(&lines, rounds, seed),
+    }
+}
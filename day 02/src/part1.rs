@@ -0,0 +1,106 @@
+use crate::game::{Game, Play, Round, ROCK_PAPER_SCISSORS};
+
+pub const GAME: Game = ROCK_PAPER_SCISSORS;
+
+// A rock, B paper, C scissors
+// X rock, Y paper, Z scissors
+pub fn map(s: &str) -> Play {
+    match s {
+        "A" | "B" | "C" => GAME.map_elf(s),
+        _ => GAME.map_me(s),
+    }
+}
+
+pub fn parse_round(game: &Game, line: &str) -> Round {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    Round {
+        elf: game.map_elf(words[0]),
+        me: game.map_me(words[1]),
+    }
+}
+
+pub fn parse_rounds(game: &Game, lines: &[String]) -> Vec<Round> {
+    lines.iter().map(|line| parse_round(game, line)).collect()
+}
+
+pub fn parse_input(lines: Vec<String>) -> Vec<Round> {
+    parse_rounds(&GAME, &lines)
+}
+
+pub fn play(round: Round) -> i32 {
+    GAME.score(&round)
+}
+
+pub fn score(game: &Game, rounds: &[Round]) -> i32 {
+    rounds.iter().map(|round| game.score(round)).sum()
+}
+
+pub fn solution(rounds: Vec<Round>) -> i32 {
+    score(&GAME, &rounds)
+}
+
+pub fn solve(lines: &[String]) -> i32 {
+    solution(parse_input(lines.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{PAPER, ROCK, ROCK_PAPER_SCISSORS_LIZARD_SPOCK, SCISSORS};
+
+    #[test]
+    fn test_parse() {
+        let lines = ["A Y", "B X", "C Z"];
+        let rounds = parse_input(lines.iter().map(|x| x.to_string()).collect());
+        assert_eq!(
+            rounds,
+            vec![
+                Round {
+                    elf: ROCK,
+                    me: PAPER
+                },
+                Round {
+                    elf: PAPER,
+                    me: ROCK
+                },
+                Round {
+                    elf: SCISSORS,
+                    me: SCISSORS
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let rounds = vec![
+            Round {
+                elf: ROCK,
+                me: PAPER,
+            },
+            Round {
+                elf: PAPER,
+                me: ROCK,
+            },
+            Round {
+                elf: SCISSORS,
+                me: SCISSORS,
+            },
+        ];
+        assert_eq!(solution(rounds), 15);
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let game = &ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+        let round = |line| score(game, &[parse_round(game, line)]);
+        // Spock vaporizes rock: a win with Spock
+        assert_eq!(round("A W"), 5 + 6);
+        // paper against paper
+        assert_eq!(round("C X"), 2 + 3);
+        // scissors decapitate lizard: a loss with lizard
+        assert_eq!(round("E Y"), 4);
+        let lines = ["A W", "C X", "E Y"].map(|x| x.to_string());
+        assert_eq!(score(game, &parse_rounds(game, &lines)), 11 + 5 + 4);
+    }
+}
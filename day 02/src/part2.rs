@@ -0,0 +1,138 @@
+use crate::game::{self, Game, Outcome, Play, ROCK_PAPER_SCISSORS};
+
+pub const GAME: Game = ROCK_PAPER_SCISSORS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub elf: Play,
+    pub outcome: Outcome,
+}
+
+impl Round {
+    /// The round as played, once I've picked the move that gets the outcome.
+    pub fn resolve(&self, game: &Game) -> game::Round {
+        game::Round {
+            elf: self.elf,
+            me: game.response(self.elf, self.outcome),
+        }
+    }
+}
+
+// A rock, B paper, C scissors
+pub fn map_play(s: &str) -> Play {
+    GAME.map_elf(s)
+}
+
+// X lose, Y draw, Z win, whatever the game
+pub fn parse_outcome(s: &str) -> Option<Outcome> {
+    match s {
+        "X" => Some(Outcome::Loss),
+        "Y" => Some(Outcome::Draw),
+        "Z" => Some(Outcome::Win),
+        _ => None,
+    }
+}
+
+pub fn map_outcome(s: &str) -> Outcome {
+    parse_outcome(s).unwrap()
+}
+
+/// None if the guide uses letters other than X, Y and Z in my column, which
+/// it may for games with more than three moves.
+pub fn try_parse_rounds(game: &Game, lines: &[String]) -> Option<Vec<Round>> {
+    lines
+        .iter()
+        .map(|line| {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            Some(Round {
+                elf: game.map_elf(words[0]),
+                outcome: parse_outcome(words[1])?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_rounds(game: &Game, lines: &[String]) -> Vec<Round> {
+    try_parse_rounds(game, lines).unwrap()
+}
+
+pub fn parse_input(lines: Vec<String>) -> Vec<Round> {
+    parse_rounds(&GAME, &lines)
+}
+
+pub fn play(round: &Round) -> i32 {
+    GAME.score(&round.resolve(&GAME))
+}
+
+pub fn score(game: &Game, rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|round| game.score(&round.resolve(game)))
+        .sum()
+}
+
+pub fn solution(rounds: Vec<Round>) -> i32 {
+    score(&GAME, &rounds)
+}
+
+pub fn solve(lines: &[String]) -> i32 {
+    solution(parse_input(lines.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{PAPER, ROCK, ROCK_PAPER_SCISSORS_LIZARD_SPOCK, SCISSORS};
+
+    #[test]
+    fn test_parse() {
+        let lines = ["A Y", "B X", "C Z"];
+        let rounds = parse_input(lines.iter().map(|x| x.to_string()).collect());
+        assert_eq!(
+            rounds,
+            vec![
+                Round {
+                    elf: ROCK,
+                    outcome: Outcome::Draw
+                },
+                Round {
+                    elf: PAPER,
+                    outcome: Outcome::Loss
+                },
+                Round {
+                    elf: SCISSORS,
+                    outcome: Outcome::Win
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let rounds = vec![
+            Round {
+                elf: ROCK,
+                outcome: Outcome::Draw,
+            },
+            Round {
+                elf: PAPER,
+                outcome: Outcome::Loss,
+            },
+            Round {
+                elf: SCISSORS,
+                outcome: Outcome::Win,
+            },
+        ];
+        assert_eq!(solution(rounds), 12);
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        // lose to rock with scissors, draw paper, beat lizard with scissors
+        let game = &ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+        let lines = ["A X", "C Y", "D Z"];
+        let rounds = parse_rounds(game, &lines.map(|x| x.to_string()));
+        assert_eq!(score(game, &rounds), 3 + (2 + 3) + (3 + 6));
+        assert_eq!(try_parse_rounds(game, &["A W".to_string()]), None);
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::{
+    game::{Play, Round},
+    part1::{self, GAME},
+    part2,
+};
+
+const LETTERS: [&str; 3] = ["X", "Y", "Z"];
+
+/// One way of reading my column of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    // the moves X, Y and Z stand for
+    Moves([Play; 3]),
+    Outcomes,
+}
+
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reading::Moves(plays) => {
+                let names: Vec<String> = LETTERS
+                    .iter()
+                    .zip(plays)
+                    .map(|(letter, &play)| format!("{}={}", letter, GAME.name(play)))
+                    .collect();
+                write!(f, "{}", names.join(" "))
+            }
+            Reading::Outcomes => write!(f, "X=lose Y=draw Z=win"),
+        }
+    }
+}
+
+/// The six ways to assign moves to X, Y and Z, then the outcome reading.
+pub fn readings() -> Vec<Reading> {
+    let mut readings = vec![];
+    for x in GAME.plays() {
+        for y in GAME.plays().filter(|&y| y != x) {
+            for z in GAME.plays().filter(|&z| z != x && z != y) {
+                readings.push(Reading::Moves([x, y, z]));
+            }
+        }
+    }
+    readings.push(Reading::Outcomes);
+    readings
+}
+
+pub fn score(reading: Reading, lines: &[String]) -> i32 {
+    lines
+        .iter()
+        .map(|line| {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let elf = part2::map_play(words[0]);
+            match reading {
+                Reading::Moves(plays) => {
+                    let letter = LETTERS.iter().position(|&x| x == words[1]).unwrap();
+                    part1::play(Round {
+                        elf,
+                        me: plays[letter],
+                    })
+                }
+                Reading::Outcomes => part2::play(&part2::Round {
+                    elf,
+                    outcome: part2::map_outcome(words[1]),
+                }),
+            }
+        })
+        .sum()
+}
+
+pub fn score_all(lines: &[String]) -> Vec<(Reading, i32)> {
+    readings()
+        .into_iter()
+        .map(|reading| (reading, score(reading, lines)))
+        .collect()
+}
+
+// the first one wins ties, both ways
+pub fn best(scores: &[(Reading, i32)]) -> (Reading, i32) {
+    let max = scores.iter().map(|&(_, score)| score).max().unwrap();
+    *scores.iter().find(|&&(_, score)| score == max).unwrap()
+}
+
+pub fn worst(scores: &[(Reading, i32)]) -> (Reading, i32) {
+    let min = scores.iter().map(|&(_, score)| score).min().unwrap();
+    *scores.iter().find(|&&(_, score)| score == min).unwrap()
+}
+
+pub fn render(scores: &[(Reading, i32)]) -> Vec<String> {
+    let (best, worst) = (best(scores), worst(scores));
+    scores
+        .iter()
+        .map(|&entry| {
+            let mark = if entry == best {
+                "  <- best"
+            } else if entry == worst {
+                "  <- worst"
+            } else {
+                ""
+            };
+            format!("{:<30} {:>8}{}", entry.0.to_string(), entry.1, mark)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{PAPER, ROCK, SCISSORS};
+
+    fn example() -> Vec<String> {
+        ["A Y", "B X", "C Z"].map(|x| x.to_string()).to_vec()
+    }
+
+    #[test]
+    fn test_readings() {
+        let readings = readings();
+        assert_eq!(readings.len(), 7);
+        assert_eq!(readings[0], Reading::Moves([ROCK, PAPER, SCISSORS]));
+        assert_eq!(readings[0].to_string(), "X=Rock Y=Paper Z=Scissors");
+    }
+
+    #[test]
+    fn test_score_all() {
+        let scores = score_all(&example());
+        // the two parts are two of the readings
+        assert_eq!(scores[0].1, 15);
+        assert_eq!(scores[6], (Reading::Outcomes, 12));
+
+        // X=Scissors Y=Paper Z=Rock wins all three rounds
+        assert_eq!(best(&scores), (Reading::Moves([SCISSORS, PAPER, ROCK]), 24));
+        assert_eq!(worst(&scores), (Reading::Moves([ROCK, SCISSORS, PAPER]), 6));
+
+        let lines = render(&scores);
+        assert!(lines[5].ends_with("<- best"));
+        assert!(lines[1].ends_with("<- worst"));
+    }
+}
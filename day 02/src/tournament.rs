@@ -0,0 +1,305 @@
+use common::rng::Rng;
+
+use crate::game::{Game, Outcome, Play, Round};
+
+/// A player in repeated games. Rounds are seen from the player's side, so
+/// `round.me` is its own move and `round.elf` the opponent's.
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    fn choose(&mut self, game: &Game, rng: &mut Rng) -> Play;
+
+    fn observe(&mut self, _game: &Game, _round: &Round) {}
+}
+
+/// Plays the moves of a strategy guide in order, starting over at the end.
+#[derive(Clone)]
+pub struct Guide {
+    plays: Vec<Play>,
+    next: usize,
+}
+
+impl Guide {
+    /// `None` if there are no moves to play.
+    pub fn new(plays: Vec<Play>) -> Option<Self> {
+        if plays.is_empty() {
+            return None;
+        }
+        Some(Self { plays, next: 0 })
+    }
+
+    pub fn from_rounds(rounds: &[Round]) -> Option<Self> {
+        Self::new(rounds.iter().map(|round| round.me).collect())
+    }
+}
+
+impl Strategy for Guide {
+    fn name(&self) -> &str {
+        "guide"
+    }
+
+    fn choose(&mut self, _game: &Game, _rng: &mut Rng) -> Play {
+        let play = self.plays[self.next];
+        self.next = (self.next + 1) % self.plays.len();
+        play
+    }
+}
+
+pub struct Random;
+
+impl Strategy for Random {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn choose(&mut self, game: &Game, rng: &mut Rng) -> Play {
+        Play(rng.index(game.len()))
+    }
+}
+
+/// Beats whatever the opponent has played most so far.
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: Vec<usize>,
+}
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn choose(&mut self, game: &Game, rng: &mut Rng) -> Play {
+        // the first of the most played moves, when there are several
+        let most = self
+            .counts
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, &count)| count);
+        match most {
+            Some((play, _)) => game.response(Play(play), Outcome::Win),
+            None => Random.choose(game, rng),
+        }
+    }
+
+    fn observe(&mut self, game: &Game, round: &Round) {
+        self.counts.resize(game.len(), 0);
+        self.counts[round.elf.0] += 1;
+    }
+}
+
+/// Keeps a winning move, and otherwise switches to the move that would have
+/// beaten the opponent's last one.
+#[derive(Default)]
+pub struct WinStayLoseShift {
+    last: Option<Round>,
+}
+
+impl Strategy for WinStayLoseShift {
+    fn name(&self) -> &str {
+        "win-stay-lose-shift"
+    }
+
+    fn choose(&mut self, game: &Game, rng: &mut Rng) -> Play {
+        match self.last {
+            Some(round) if game.outcome(round.elf, round.me) == Outcome::Win => round.me,
+            Some(round) => game.response(round.elf, Outcome::Win),
+            None => Random.choose(game, rng),
+        }
+    }
+
+    fn observe(&mut self, _game: &Game, round: &Round) {
+        self.last = Some(*round);
+    }
+}
+
+/// Plays `rounds` rounds between `a` and `b` and returns both their scores.
+pub fn play_match(
+    game: &Game,
+    a: &mut dyn Strategy,
+    b: &mut dyn Strategy,
+    rounds: usize,
+    rng: &mut Rng,
+) -> (i32, i32) {
+    let mut scores = (0, 0);
+    for _ in 0..rounds {
+        let (play_a, play_b) = (a.choose(game, rng), b.choose(game, rng));
+        let round_a = Round {
+            elf: play_b,
+            me: play_a,
+        };
+        let round_b = Round {
+            elf: play_a,
+            me: play_b,
+        };
+        scores.0 += game.score(&round_a);
+        scores.1 += game.score(&round_b);
+        a.observe(game, &round_a);
+        b.observe(game, &round_b);
+    }
+    scores
+}
+
+/// Makes a fresh player for every match.
+pub type Entrant<'a> = Box<dyn Fn() -> Box<dyn Strategy> + 'a>;
+
+/// The match scores of one strategy over a whole tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub name: String,
+    pub scores: Vec<i32>,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Distribution {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            scores: vec![],
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        }
+    }
+
+    fn record(&mut self, score: i32, other: i32) {
+        self.scores.push(score);
+        match score.cmp(&other) {
+            std::cmp::Ordering::Greater => self.wins += 1,
+            std::cmp::Ordering::Equal => self.draws += 1,
+            std::cmp::Ordering::Less => self.losses += 1,
+        }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.scores.iter().sum::<i32>() as f64 / self.scores.len() as f64
+    }
+
+    pub fn stddev(&self) -> f64 {
+        let mean = self.mean();
+        let squares: f64 = self.scores.iter().map(|&x| (x as f64 - mean).powi(2)).sum();
+        (squares / self.scores.len() as f64).sqrt()
+    }
+
+    /// The score below which `p` percent of the matches fall.
+    pub fn percentile(&self, p: usize) -> i32 {
+        let mut scores = self.scores.clone();
+        scores.sort();
+        scores[(scores.len() - 1) * p / 100]
+    }
+}
+
+/// Every entrant plays `matches` matches of `rounds` rounds against every
+/// other one.
+pub fn tournament(
+    game: &Game,
+    entrants: &[Entrant],
+    rounds: usize,
+    matches: usize,
+    seed: u64,
+) -> Vec<Distribution> {
+    let mut rng = Rng::new(seed);
+    let mut distributions: Vec<Distribution> = entrants
+        .iter()
+        .map(|make| Distribution::new(make().name()))
+        .collect();
+    for i in 0..entrants.len() {
+        for j in i + 1..entrants.len() {
+            for _ in 0..matches {
+                let (mut a, mut b) = (entrants[i](), entrants[j]());
+                let (score_a, score_b) = play_match(game, &mut *a, &mut *b, rounds, &mut rng);
+                distributions[i].record(score_a, score_b);
+                distributions[j].record(score_b, score_a);
+            }
+        }
+    }
+    distributions
+}
+
+pub fn render(distributions: &[Distribution]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{:<20} {:>5} {:>5} {:>5} {:>10} {:>8} {:>8} {:>8} {:>8}",
+        "strategy", "won", "drawn", "lost", "mean", "stddev", "min", "median", "max"
+    )];
+    for d in distributions.iter().filter(|d| !d.scores.is_empty()) {
+        lines.push(format!(
+            "{:<20} {:>5} {:>5} {:>5} {:>10.1} {:>8.1} {:>8} {:>8} {:>8}",
+            d.name,
+            d.wins,
+            d.draws,
+            d.losses,
+            d.mean(),
+            d.stddev(),
+            d.percentile(0),
+            d.percentile(50),
+            d.percentile(100)
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{PAPER, ROCK, ROCK_PAPER_SCISSORS, SCISSORS};
+
+    const GAME: Game = ROCK_PAPER_SCISSORS;
+
+    fn rocks() -> Guide {
+        Guide::new(vec![ROCK]).unwrap()
+    }
+
+    #[test]
+    fn test_guide() {
+        let mut guide = Guide::new(vec![ROCK, SCISSORS]).unwrap();
+        let mut rng = Rng::new(0);
+        let plays: Vec<Play> = (0..3).map(|_| guide.choose(&GAME, &mut rng)).collect();
+        assert_eq!(plays, vec![ROCK, SCISSORS, ROCK]);
+        assert!(Guide::new(vec![]).is_none());
+    }
+
+    #[test]
+    fn test_adaptive() {
+        // both find paper after at most one round against a rock
+        let mut rng = Rng::new(7);
+        let (score, _) = play_match(
+            &GAME,
+            &mut FrequencyCounter::default(),
+            &mut rocks(),
+            10,
+            &mut rng,
+        );
+        assert!(score >= 9 * 8);
+
+        let mut wsls = WinStayLoseShift::default();
+        play_match(&GAME, &mut wsls, &mut rocks(), 10, &mut rng);
+        assert_eq!(wsls.choose(&GAME, &mut rng), PAPER);
+    }
+
+    #[test]
+    fn test_tournament() {
+        let entrants: Vec<Entrant> = vec![
+            Box::new(|| Box::new(rocks())),
+            Box::new(|| Box::new(Random)),
+            Box::new(|| Box::new(FrequencyCounter::default())),
+            Box::new(|| Box::new(WinStayLoseShift::default())),
+        ];
+        let distributions = tournament(&GAME, &entrants, 100, 5, 42);
+        assert_eq!(distributions, tournament(&GAME, &entrants, 100, 5, 42));
+
+        let names: Vec<&str> = distributions.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["guide", "random", "frequency", "win-stay-lose-shift"]
+        );
+        for d in distributions.iter() {
+            assert_eq!(d.scores.len(), 3 * 5);
+            assert_eq!(d.wins + d.draws + d.losses, 15);
+        }
+        // the guide never wins against the adaptive strategies
+        assert!(distributions[0].wins <= 5);
+        assert_eq!(render(&distributions).len(), 5);
+    }
+}
@@ -0,0 +1,321 @@
+use std::fmt;
+
+use crate::rucksack::{common, compartments, item, items, Items};
+
+/// Something wrong with the input. Lines and groups are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    UnevenLength {
+        line: usize,
+        len: usize,
+        compartments: usize,
+    },
+    InvalidItem {
+        line: usize,
+        column: usize,
+        item: char,
+    },
+    NoSharedItem {
+        line: usize,
+    },
+    SharedItems {
+        line: usize,
+        items: Items,
+    },
+    NoBadge {
+        group: usize,
+    },
+    MultipleBadges {
+        group: usize,
+        badges: Items,
+    },
+    IncompleteGroup {
+        lines: usize,
+        size: usize,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::UnevenLength {
+                line,
+                len,
+                compartments,
+            } => write!(
+                f,
+                "line {}: {} items don't split into {} compartments",
+                line, len, compartments
+            ),
+            Diagnostic::InvalidItem { line, column, item } => {
+                write!(
+                    f,
+                    "line {}, column {}: not an item {:?}",
+                    line, column, item
+                )
+            }
+            Diagnostic::NoSharedItem { line } => {
+                write!(f, "line {}: no item in every compartment", line)
+            }
+            Diagnostic::SharedItems { line, items } => write!(
+                f,
+                "line {}: several items in every compartment ({})",
+                line,
+                list(*items)
+            ),
+            Diagnostic::NoBadge { group } => write!(f, "group {}: no badge", group),
+            Diagnostic::MultipleBadges { group, badges } => {
+                write!(f, "group {}: several badges ({})", group, list(*badges))
+            }
+            Diagnostic::IncompleteGroup { lines, size } => write!(
+                f,
+                "{} lines don't make groups of {}, the last group is left out",
+                lines, size
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    pub line: usize,
+    // None when the rucksack can't be split into compartments
+    pub shared: Option<Items>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub group: usize,
+    pub first_line: usize,
+    pub badges: Items,
+}
+
+#[derive(Debug, Default)]
+pub struct Audit {
+    pub rucksacks: Vec<Rucksack>,
+    pub groups: Vec<Group>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The items in a set, in priority order.
+pub fn list(items: Items) -> String {
+    (1..=52)
+        .filter(|&p| items & 1 << p != 0)
+        .map(item)
+        .collect()
+}
+
+// anything that isn't a letter ends up as bit 0, which is left out of the
+// shared items so it's only reported once
+const VALID: Items = !1;
+
+// every character of line `line` that isn't an item
+fn invalid_items(line: usize, text: &str) -> impl Iterator<Item = Diagnostic> + '_ {
+    text.chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_ascii_alphabetic())
+        .map(move |(column, item)| Diagnostic::InvalidItem {
+            line,
+            column: column + 1,
+            item,
+        })
+}
+
+/// The first thing in the input that would make the answers wrong: something
+/// that isn't an item, a rucksack that doesn't split into `k` compartments,
+/// or a last group with fewer than `size` rucksacks.
+pub fn validate(lines: &[String], k: usize, size: usize) -> Result<(), Diagnostic> {
+    for (i, text) in lines.iter().enumerate() {
+        let line = i + 1;
+        if let Some(diagnostic) = invalid_items(line, text).next() {
+            return Err(diagnostic);
+        }
+        if !text.len().is_multiple_of(k) {
+            return Err(Diagnostic::UnevenLength {
+                line,
+                len: text.len(),
+                compartments: k,
+            });
+        }
+    }
+    if !lines.len().is_multiple_of(size) {
+        return Err(Diagnostic::IncompleteGroup {
+            lines: lines.len(),
+            size,
+        });
+    }
+    Ok(())
+}
+
+/// Checks every rucksack split in `k` compartments and every group of `size`
+/// rucksacks, without stopping at the first problem.
+pub fn audit(lines: &[String], k: usize, size: usize) -> Audit {
+    let mut audit = Audit::default();
+    for (i, text) in lines.iter().enumerate() {
+        let line = i + 1;
+        audit.diagnostics.extend(invalid_items(line, text));
+
+        let mut shared = None;
+        // compartments are split by bytes, which only works out for ascii
+        if !text.len().is_multiple_of(k) || !text.is_ascii() {
+            audit.diagnostics.push(Diagnostic::UnevenLength {
+                line,
+                len: text.chars().count(),
+                compartments: k,
+            });
+        } else {
+            let items = common(compartments(text, k)) & VALID;
+            match items.count_ones() {
+                0 => audit.diagnostics.push(Diagnostic::NoSharedItem { line }),
+                1 => {}
+                _ => audit
+                    .diagnostics
+                    .push(Diagnostic::SharedItems { line, items }),
+            }
+            shared = Some(items);
+        }
+        audit.rucksacks.push(Rucksack { line, shared });
+    }
+
+    for (i, chunk) in lines.chunks(size).enumerate() {
+        if chunk.len() < size {
+            audit.diagnostics.push(Diagnostic::IncompleteGroup {
+                lines: lines.len(),
+                size,
+            });
+            break;
+        }
+        let group = i + 1;
+        let badges = common(chunk.iter().map(|line| items(line))) & VALID;
+        match badges.count_ones() {
+            0 => audit.diagnostics.push(Diagnostic::NoBadge { group }),
+            1 => {}
+            _ => audit
+                .diagnostics
+                .push(Diagnostic::MultipleBadges { group, badges }),
+        }
+        audit.groups.push(Group {
+            group,
+            first_line: i * size + 1,
+            badges,
+        });
+    }
+    audit
+}
+
+pub fn render(audit: &Audit) -> Vec<String> {
+    let shown = |items: Option<Items>| match items {
+        Some(items) if items != 0 => list(items),
+        _ => "-".to_string(),
+    };
+    let mut lines = vec![];
+    for rucksack in audit.rucksacks.iter() {
+        lines.push(format!(
+            "line {}: {}",
+            rucksack.line,
+            shown(rucksack.shared)
+        ));
+    }
+    for group in audit.groups.iter() {
+        lines.push(format!(
+            "group {} (from line {}): {}",
+            group.group,
+            group.first_line,
+            shown(Some(group.badges))
+        ));
+    }
+    lines.extend(audit.diagnostics.iter().map(|d| d.to_string()));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_clean() {
+        let example = lines(&[
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ]);
+        let audit = audit(&example, 2, 3);
+        assert_eq!(audit.diagnostics, vec![]);
+        let shared: Vec<String> = audit
+            .rucksacks
+            .iter()
+            .map(|r| list(r.shared.unwrap()))
+            .collect();
+        assert_eq!(shared, vec!["p", "L", "P"]);
+        assert_eq!(list(audit.groups[0].badges), "r");
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let input = lines(&["abcab", "abab", "ab-d", "xyzw", "abcd"]);
+        let audit = audit(&input, 2, 2);
+        assert_eq!(
+            audit.diagnostics,
+            vec![
+                Diagnostic::UnevenLength {
+                    line: 1,
+                    len: 5,
+                    compartments: 2
+                },
+                Diagnostic::SharedItems {
+                    line: 2,
+                    items: items("ab")
+                },
+                Diagnostic::InvalidItem {
+                    line: 3,
+                    column: 3,
+                    item: '-'
+                },
+                Diagnostic::NoSharedItem { line: 3 },
+                Diagnostic::NoSharedItem { line: 4 },
+                Diagnostic::NoSharedItem { line: 5 },
+                Diagnostic::MultipleBadges {
+                    group: 1,
+                    badges: items("ab")
+                },
+                Diagnostic::NoBadge { group: 2 },
+                Diagnostic::IncompleteGroup { lines: 5, size: 2 },
+            ]
+        );
+        assert_eq!(audit.rucksacks[0].shared, None);
+        assert_eq!(audit.groups.len(), 2);
+        assert_eq!(
+            audit.diagnostics[1].to_string(),
+            "line 2: several items in every compartment (ab)"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(&lines(&["abAB", ""]), 2, 2), Ok(()));
+        assert_eq!(
+            validate(&lines(&["ab", "aébc", "a-"]), 2, 3),
+            Err(Diagnostic::InvalidItem {
+                line: 2,
+                column: 2,
+                item: 'é'
+            })
+        );
+        assert_eq!(
+            validate(&lines(&["abc", "abcdefghij"]), 3, 2),
+            Err(Diagnostic::UnevenLength {
+                line: 2,
+                len: 10,
+                compartments: 3
+            })
+        );
+        assert_eq!(
+            validate(&lines(&["abXcdX", "XefXgh", "ijXklX", "mnXopX"]), 2, 3),
+            Err(Diagnostic::IncompleteGroup { lines: 4, size: 3 })
+        );
+    }
+}
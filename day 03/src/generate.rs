@@ -0,0 +1,97 @@
+use common::rng::Rng;
+
+use crate::rucksack::item;
+
+pub struct Generated {
+    pub lines: Vec<String>,
+    // what the two parts should come up with, for compartment halves and the
+    // group size it was generated for
+    pub compartment_sum: u32,
+    pub group_sum: u32,
+}
+
+/// Generates `groups` groups of `size` rucksacks holding `len` items each,
+/// where the two halves of every rucksack share exactly one item and every
+/// group exactly one badge.
+pub fn generate(rng: &mut Rng, groups: usize, size: usize, len: usize) -> Generated {
+    assert!(size >= 2, "groups need at least two rucksacks");
+    assert!(
+        len >= 4 && len.is_multiple_of(2),
+        "rucksacks need an even length of 4 or more"
+    );
+    let mut generated = Generated {
+        lines: Vec::with_capacity(groups * size),
+        compartment_sum: 0,
+        group_sum: 0,
+    };
+    for _ in 0..groups {
+        let badge = rng.between(1, 52) as u32;
+        generated.group_sum += badge;
+        for member in 0..size {
+            // every other item is left out of one of the rucksacks, so the
+            // badge is the only one they all have
+            let mut pool: Vec<u32> = (1..=52)
+                .filter(|&p| p != badge && p as usize % size != member)
+                .collect();
+            rng.shuffle(&mut pool);
+            let shared = pool.pop().unwrap();
+            generated.compartment_sum += shared;
+
+            // and the halves draw from separate pools, so `shared` is the
+            // only item in both
+            let (left_pool, right_pool) = pool.split_at(pool.len() / 2);
+            let mut left = vec![badge, shared];
+            let mut right = vec![shared];
+            left.extend((2..len / 2).map(|_| left_pool[rng.index(left_pool.len())]));
+            right.extend((1..len / 2).map(|_| right_pool[rng.index(right_pool.len())]));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            generated
+                .lines
+                .push(left.into_iter().chain(right).map(item).collect());
+        }
+    }
+    generated
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::{part1, part2, rucksack::*};
+
+    #[test]
+    fn test_generate() {
+        let generated = generate(&mut Rng::new(3), 20_000, part2::GROUP_SIZE, 48);
+        assert_eq!(generated.lines.len(), 60_000);
+        assert!(generated.lines.iter().all(|line| line.len() == 48));
+        assert_eq!(part1::solve(&generated.lines), generated.compartment_sum);
+        assert_eq!(part2::solve(&generated.lines), generated.group_sum);
+    }
+
+    #[test]
+    fn test_group_sizes() {
+        for size in [2, 5, 60] {
+            let generated = generate(&mut Rng::new(size as u64), 50, size, 10);
+            let groups = by_groups(&generated.lines, size);
+            assert_eq!(shared_sum(&groups), generated.group_sum);
+            assert!(groups
+                .iter()
+                .all(|group| common(group.iter().copied()).count_ones() == 1));
+        }
+    }
+
+    #[test]
+    fn test_throughput() {
+        // far below what either part manages even in a debug build, but
+        // enough to catch anything that stops being linear
+        const MIN_LINES_PER_SECOND: f64 = 50_000.0;
+        let generated = generate(&mut Rng::new(4), 100_000, part2::GROUP_SIZE, 48);
+        let start = Instant::now();
+        assert_eq!(part1::solve(&generated.lines), generated.compartment_sum);
+        assert_eq!(part2::solve(&generated.lines), generated.group_sum);
+        let rate = 2.0 * generated.lines.len() as f64 / start.elapsed().as_secs_f64();
+        assert!(rate > MIN_LINES_PER_SECOND, "{:.0} lines/s", rate);
+    }
+}
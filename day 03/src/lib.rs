@@ -0,0 +1,5 @@
+pub mod audit;
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod rucksack;
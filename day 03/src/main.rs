@@ -0,0 +1,88 @@
+use std::{
+    env,
+    io::{self, BufRead},
+    process,
+};
+
+use common::rng::Rng;
+use day03::{
+    audit::{audit, render, validate},
+    generate::generate,
+    part1, part2,
+    rucksack::{by_compartments, by_groups, shared_sum},
+};
+
+const GENERATED_LENGTH: usize = 48;
+const SEED: u64 = 2022;
+
+fn usage() -> ! {
+    eprintln!("usage: day03 [<group size> <compartments>] < input.txt");
+    eprintln!("       day03 --audit < input.txt");
+    eprintln!("       day03 --generate <groups> [seed] > input.txt");
+    process::exit(2);
+}
+
+fn read_lines() -> Vec<String> {
+    let lines = io::stdin().lock().lines();
+    lines.map(|line| line.unwrap()).collect()
+}
+
+// the input, if it makes sense split in `k` compartments and groups of
+// `size`; `--audit` tells more about what's wrong
+fn read_items(k: usize, size: usize) -> Vec<String> {
+    let lines = read_lines();
+    if let Err(diagnostic) = validate(&lines, k, size) {
+        eprintln!("{}", diagnostic);
+        process::exit(1);
+    }
+    lines
+}
+
+fn print_generated(groups: usize, seed: u64) {
+    let mut rng = Rng::new(seed);
+    let generated = generate(&mut rng, groups, part2::GROUP_SIZE, GENERATED_LENGTH);
+    for line in generated.lines {
+        println!("{}", line);
+    }
+}
+
+// every shared item and badge, then whatever looks wrong, failing if anything
+// does
+fn print_audit() {
+    let audit = audit(&read_lines(), part1::COMPARTMENTS, part2::GROUP_SIZE);
+    for line in render(&audit) {
+        println!("{}", line);
+    }
+    if !audit.diagnostics.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    let number = |arg: &str| arg.parse().unwrap_or_else(|_| usage());
+    // group sizes and compartment counts start at 1
+    let count = |arg: &str| match number(arg) {
+        0 => usage(),
+        n => n,
+    };
+    match args[..] {
+        [] => {
+            let lines = read_items(part1::COMPARTMENTS, part2::GROUP_SIZE);
+            println!("{}", part1::solve(&lines));
+            println!("{}", part2::solve(&lines));
+        }
+        ["--audit"] => print_audit(),
+        // inputs as big as needed, for `aoc bench 3`
+        ["--generate", groups] => print_generated(number(groups), SEED),
+        ["--generate", groups, seed] => print_generated(number(groups), number(seed) as u64),
+        [size, k] => {
+            let (size, k) = (count(size), count(k));
+            let lines = read_items(k, size);
+            println!("{}", shared_sum(&by_compartments(&lines, k)));
+            println!("{}", shared_sum(&by_groups(&lines, size)));
+        }
+        _ => usage(),
+    }
+}
@@ -0,0 +1,44 @@
+use crate::rucksack::{by_groups, shared_sum, Items};
+
+pub const GROUP_SIZE: usize = 3;
+
+pub fn parse_input(lines: &[String]) -> Vec<Vec<Items>> {
+    by_groups(lines, GROUP_SIZE)
+}
+
+pub fn solution(groups: Vec<Vec<Items>>) -> u32 {
+    shared_sum(&groups)
+}
+
+pub fn solve(lines: &[String]) -> u32 {
+    solution(parse_input(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rucksack::letter_value;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(letter_value('a'), 1);
+        assert_eq!(letter_value('z'), 26);
+        assert_eq!(letter_value('A'), 27);
+        assert_eq!(letter_value('Z'), 52);
+    }
+
+    #[test]
+    fn test_example() {
+        let lines = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ];
+        let lines: Vec<String> = lines.iter().map(|x| x.to_string()).collect();
+        let pairs = parse_input(&lines);
+        assert_eq!(solution(pairs), 70);
+    }
+}
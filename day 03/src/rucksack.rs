@@ -0,0 +1,114 @@
+/// A set of items as a bitmask, with bit `p` set for the item of priority `p`.
+pub type Items = u64;
+
+pub fn letter_value(c: char) -> u32 {
+    match c {
+        'a'..='z' => c as u32 - 'a' as u32 + 1,
+        'A'..='Z' => c as u32 - 'A' as u32 + 27,
+        _ => 0,
+    }
+}
+
+/// The item with the given priority, from 1 to 52.
+pub fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + priority as u8 - 1) as char,
+        27..=52 => (b'A' + priority as u8 - 27) as char,
+        _ => panic!("no item has priority {}", priority),
+    }
+}
+
+pub fn items(s: &str) -> Items {
+    s.chars().fold(0, |items, c| items | 1 << letter_value(c))
+}
+
+/// The sum of the priorities of every item in the set.
+pub fn priorities(mut items: Items) -> u32 {
+    let mut sum = 0;
+    while items != 0 {
+        sum += items.trailing_zeros();
+        items &= items - 1;
+    }
+    sum
+}
+
+/// The items every set has in common.
+pub fn common<I: IntoIterator<Item = Items>>(sets: I) -> Items {
+    sets.into_iter().fold(!0, |common, items| common & items)
+}
+
+/// Splits a rucksack into `k` compartments of the same size, `k` being at
+/// least 1. Items past the last whole compartment are left out, `validate`
+/// turns such rucksacks away. Split by bytes, so anything that isn't ascii counts as no item,
+/// like any other non-letter.
+pub fn compartments(line: &str, k: usize) -> Vec<Items> {
+    assert!(k > 0, "a rucksack has at least one compartment");
+    let bytes = line.as_bytes();
+    let size = bytes.len() / k;
+    (0..k)
+        .map(|i| {
+            bytes[i * size..(i + 1) * size]
+                .iter()
+                .fold(0, |items, &b| items | 1 << letter_value(b as char))
+        })
+        .collect()
+}
+
+/// Every rucksack split into `k` compartments.
+pub fn by_compartments(lines: &[String], k: usize) -> Vec<Vec<Items>> {
+    lines.iter().map(|line| compartments(line, k)).collect()
+}
+
+/// Every group of `size` consecutive rucksacks, `size` being at least 1. A
+/// last group with fewer rucksacks is left out.
+pub fn by_groups(lines: &[String], size: usize) -> Vec<Vec<Items>> {
+    lines
+        .chunks_exact(size)
+        .map(|group| group.iter().map(|line| items(line)).collect())
+        .collect()
+}
+
+/// The priorities of the items shared within each list of sets, added up.
+pub fn shared_sum(sets: &[Vec<Items>]) -> u32 {
+    sets.iter()
+        .map(|sets| priorities(common(sets.iter().copied())))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items() {
+        assert!((1..=52).all(|p| letter_value(item(p)) == p));
+        assert_eq!(items("aaZ"), 1 << 1 | 1 << 52);
+        assert_eq!(priorities(items("aaZ")), 53);
+        assert_eq!(
+            common([items("abc"), items("bcd"), items("cb")]),
+            items("bc")
+        );
+        assert_eq!(compartments("abcabd", 2), vec![items("abc"), items("abd")]);
+        assert_eq!(
+            compartments("abcabd", 3),
+            vec![items("ab"), items("ca"), items("bd")]
+        );
+        // é is two bytes, neither of them an item, like ?
+        assert_eq!(compartments("aébcd", 2), vec![items("a?"), items("bcd")]);
+    }
+
+    #[test]
+    fn test_sizes() {
+        let lines: Vec<String> = ["abXcdX", "XefXgh", "ijXklX", "mnXopX"]
+            .map(|x| x.to_string())
+            .to_vec();
+        let x = letter_value('X');
+        assert_eq!(shared_sum(&by_groups(&lines, 2)), 2 * x);
+        assert_eq!(shared_sum(&by_groups(&lines, 4)), x);
+        // the fourth rucksack isn't a group of its own
+        assert_eq!(shared_sum(&by_groups(&lines, 3)), x);
+        assert_eq!(shared_sum(&by_compartments(&lines, 2)), 4 * x);
+        // no rucksack has an X in each third
+        assert_eq!(shared_sum(&by_compartments(&lines, 3)), 0);
+    }
+}
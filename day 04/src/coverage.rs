@@ -0,0 +1,181 @@
+use crate::range::{IntervalSet, Range};
+
+/// Sections across the whole assignment list, every elf counted on its own.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Coverage {
+    // assigned to at least one elf
+    pub covered: IntervalSet,
+    // assigned to two elves or more
+    pub overlapped: IntervalSet,
+    // between the first and last covered section, assigned to nobody
+    pub gaps: IntervalSet,
+}
+
+/// How many elves are assigned to each section, as runs of sections with the
+/// same (non-zero) depth in order. Sorting the start and end of every range
+/// makes it O(n log n) in the number of elves, whatever the sections.
+pub fn profile(pairs: &[(Range, Range)]) -> Vec<(Range, usize)> {
+    let mut events: Vec<(i32, i32)> = pairs
+        .iter()
+        .flat_map(|&(a, b)| [a, b])
+        .filter(|range| !range.is_empty())
+        .flat_map(|range| [(range.start, 1), (range.end + 1, -1)])
+        .collect();
+    events.sort_unstable();
+
+    let mut profile: Vec<(Range, usize)> = vec![];
+    let mut depth = 0;
+    for (i, &(section, change)) in events.iter().enumerate() {
+        depth += change;
+        let Some(&(next, _)) = events.get(i + 1) else {
+            break;
+        };
+        if next == section || depth == 0 {
+            continue;
+        }
+        match profile.last_mut() {
+            Some((range, last)) if *last == depth as usize && range.end + 1 == section => {
+                range.end = next - 1
+            }
+            _ => profile.push((
+                Range {
+                    start: section,
+                    end: next - 1,
+                },
+                depth as usize,
+            )),
+        }
+    }
+    profile
+}
+
+/// The sections assigned to `k` elves or more.
+pub fn at_least(profile: &[(Range, usize)], k: usize) -> IntervalSet {
+    profile
+        .iter()
+        .filter(|&&(_, depth)| depth >= k)
+        .map(|&(range, _)| range)
+        .collect()
+}
+
+pub fn coverage(pairs: &[(Range, Range)]) -> Coverage {
+    let profile = profile(pairs);
+    let covered = at_least(&profile, 1);
+    let gaps = covered.gaps();
+    Coverage {
+        covered,
+        overlapped: at_least(&profile, 2),
+        gaps,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Depth {
+    // the most elves assigned to one section, and those sections
+    pub max: usize,
+    pub deepest: IntervalSet,
+    // the sections with at least `k` elves
+    pub k: usize,
+    pub overlaps: IntervalSet,
+}
+
+pub fn depth(pairs: &[(Range, Range)], k: usize) -> Depth {
+    let profile = profile(pairs);
+    let max = profile.iter().map(|&(_, depth)| depth).max().unwrap_or(0);
+    Depth {
+        max,
+        deepest: at_least(&profile, max.max(1)),
+        k,
+        overlaps: at_least(&profile, k),
+    }
+}
+
+fn ranges(set: &IntervalSet) -> String {
+    let ranges: Vec<String> = set.ranges().iter().map(|range| range.to_string()).collect();
+    ranges.join(" ")
+}
+
+fn sections(name: &str, set: &IntervalSet) -> String {
+    format!("{}: {} sections {}", name, set.len(), ranges(set))
+        .trim_end()
+        .to_string()
+}
+
+pub fn render(coverage: &Coverage) -> Vec<String> {
+    vec![
+        sections("covered", &coverage.covered),
+        sections("overlapped", &coverage.overlapped),
+        sections("gaps", &coverage.gaps),
+    ]
+}
+
+pub fn render_depth(depth: &Depth) -> Vec<String> {
+    vec![
+        format!("max depth: {}", depth.max),
+        sections("at max depth", &depth.deepest),
+        sections(&format!("at depth {} or more", depth.k), &depth.overlaps),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::parse_input;
+
+    #[test]
+    fn test_coverage() {
+        let lines: Vec<String> = [
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ]
+        .iter()
+        .map(|x| x.to_string())
+        .collect();
+        let example = coverage(&parse_input(&lines));
+        assert_eq!(example.covered.ranges(), &[Range::new("2-9")]);
+        assert_eq!(example.overlapped.ranges(), &[Range::new("2-8")]);
+        assert!(example.gaps.is_empty());
+
+        let lines = vec!["1-3,2-5".to_string(), "9-9,12-14".to_string()];
+        assert_eq!(
+            render(&coverage(&parse_input(&lines))),
+            vec![
+                "covered: 9 sections 1-5 9-9 12-14",
+                "overlapped: 2 sections 2-3",
+                "gaps: 5 sections 6-8 10-11",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_depth() {
+        let lines: Vec<String> = ["1-10,3-5", "4-4,8-12", "2-3,20-19"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        let pairs = parse_input(&lines);
+        assert_eq!(
+            profile(&pairs),
+            vec![
+                (Range::new("1-1"), 1),
+                (Range::new("2-2"), 2),
+                (Range::new("3-4"), 3),
+                (Range::new("5-5"), 2),
+                (Range::new("6-7"), 1),
+                (Range::new("8-10"), 2),
+                (Range::new("11-12"), 1),
+            ]
+        );
+        let depth = depth(&pairs, 2);
+        assert_eq!(depth.max, 3);
+        assert_eq!(depth.deepest.ranges(), &[Range::new("3-4")]);
+        assert_eq!(
+            render_depth(&depth),
+            vec![
+                "max depth: 3",
+                "at max depth: 2 sections 3-4",
+                "at depth 2 or more: 7 sections 2-5 8-10",
+            ]
+        );
+        assert_eq!(super::depth(&[], 2).max, 0);
+    }
+}
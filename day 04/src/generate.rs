@@ -0,0 +1,126 @@
+use std::iter;
+
+use common::rng::Rng;
+
+use crate::range::Range;
+
+// makes both ranges of a line
+type Pair = fn(&mut Rng, Range) -> (Range, Range);
+
+fn range(start: i64, end: i64) -> Range {
+    Range {
+        start: start as i32,
+        end: end as i32,
+    }
+}
+
+// three different sections in order
+fn three(rng: &mut Rng, sections: Range) -> (i64, i64, i64) {
+    let (lo, hi) = (sections.start as i64, sections.end as i64);
+    loop {
+        let mut picks = [
+            rng.between(lo, hi),
+            rng.between(lo, hi),
+            rng.between(lo, hi),
+        ];
+        picks.sort();
+        if picks[0] < picks[1] && picks[1] < picks[2] {
+            return (picks[0], picks[1], picks[2]);
+        }
+    }
+}
+
+fn contained(rng: &mut Rng, sections: Range) -> (Range, Range) {
+    let (lo, hi) = (sections.start as i64, sections.end as i64);
+    let start = rng.between(lo, hi);
+    let end = rng.between(start, hi);
+    let inner_start = rng.between(start, end);
+    let inner_end = rng.between(inner_start, end);
+    (range(start, end), range(inner_start, inner_end))
+}
+
+// overlapping, with each range sticking out on its own side
+fn overlapping(rng: &mut Rng, sections: Range) -> (Range, Range) {
+    let (x, y, z) = three(rng, sections);
+    (range(x, y), range(rng.between(x + 1, y), z))
+}
+
+fn disjoint(rng: &mut Rng, sections: Range) -> (Range, Range) {
+    let (lo, hi) = (sections.start as i64, sections.end as i64);
+    let (x, _, z) = three(rng, sections);
+    let end = rng.between(x, z - 1);
+    let start = rng.between(end + 1, z);
+    (
+        range(rng.between(lo, end), end),
+        range(start, rng.between(start, hi)),
+    )
+}
+
+/// Generates `lines` pairs of assignments within `sections`, where exactly
+/// `contained` pairs have one range inside the other (part 1) and exactly
+/// `overlapping` pairs overlap at all (part 2, so it includes `contained`).
+pub fn generate(
+    rng: &mut Rng,
+    lines: usize,
+    sections: Range,
+    contained: usize,
+    overlapping: usize,
+) -> Vec<String> {
+    assert!(contained <= overlapping && overlapping <= lines);
+    assert!(sections.len() >= 3, "need at least 3 sections to pick from");
+    // `-` separates the ends of a range, so there's no writing negative ones
+    assert!(sections.start >= 0, "sections can't be negative");
+    let mut kinds: Vec<Pair> = vec![];
+    kinds.extend(iter::repeat_n(self::contained as Pair, contained));
+    kinds.extend(iter::repeat_n(
+        self::overlapping as Pair,
+        overlapping - contained,
+    ));
+    kinds.extend(iter::repeat_n(disjoint as Pair, lines - overlapping));
+    rng.shuffle(&mut kinds);
+    kinds
+        .into_iter()
+        .map(|kind| {
+            let (mut a, mut b) = kind(rng, sections);
+            if rng.chance(0.5) {
+                (a, b) = (b, a);
+            }
+            format!("{},{}", a, b)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() {
+        let mut rng = Rng::new(4);
+        for (lines, sections, contained, overlapping) in [
+            (1000, "1-99", 500, 800),
+            (300, "1-3", 0, 0),
+            (300, "10-12", 100, 100),
+            (50, "0-5", 0, 50),
+        ] {
+            let sections = Range::new(sections);
+            let generated = generate(&mut rng, lines, sections, contained, overlapping);
+            assert_eq!(generated.len(), lines);
+
+            let pairs = part1::parse_input(&generated);
+            let written: Vec<String> = pairs.iter().map(|(a, b)| format!("{},{}", a, b)).collect();
+            assert_eq!(written, generated);
+            assert!(pairs
+                .iter()
+                .flat_map(|&(a, b)| [a, b])
+                .all(|range| !range.is_empty() && sections.contains(&range)));
+
+            assert_eq!(part1::solution(pairs), contained as u32);
+            assert_eq!(
+                part2::solution(part2::parse_input(&generated)),
+                overlapping as u32
+            );
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::{
+    env,
+    io::{self, BufRead},
+    process,
+};
+
+use common::rng::Rng;
+use day04::{coverage, generate::generate, part1, part2, range::Range};
+
+fn usage() -> ! {
+    eprintln!("usage: day04 [--coverage] < input.txt");
+    eprintln!("       day04 --depth [k] < input.txt");
+    eprintln!("       day04 --generate <lines> <contained> <overlapping> [seed] > input.txt");
+    process::exit(2);
+}
+
+// sections assigned to this many elves count as overlapping
+const OVERLAP: usize = 2;
+
+// generated inputs use the same sections as the puzzle input
+const SECTIONS: Range = Range { start: 1, end: 99 };
+const SEED: u64 = 2022;
+
+enum Mode {
+    Solve,
+    Coverage,
+    Depth(usize),
+    // lines, contained and overlapping pairs, seed
+    Generate(usize, usize, usize, u64),
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    let number = |arg: &str| arg.parse().unwrap_or_else(|_| usage());
+    let mode = match args[..] {
+        [] => Mode::Solve,
+        ["--coverage"] => Mode::Coverage,
+        ["--depth"] => Mode::Depth(OVERLAP),
+        ["--depth", k] => Mode::Depth(number(k)),
+        ["--generate", lines, contained, overlapping] => {
+            Mode::Generate(number(lines), number(contained), number(overlapping), SEED)
+        }
+        ["--generate", lines, contained, overlapping, seed] => Mode::Generate(
+            number(lines),
+            number(contained),
+            number(overlapping),
+            number(seed) as u64,
+        ),
+        _ => usage(),
+    };
+    let input = || -> Vec<String> {
+        let lines = io::stdin().lock().lines();
+        lines.map(|line| line.unwrap()).collect()
+    };
+    let output = match mode {
+        Mode::Solve => {
+            let lines = input();
+            vec![
+                part1::solve(&lines).to_string(),
+                part2::solve(&lines).to_string(),
+            ]
+        }
+        Mode::Coverage => coverage::render(&coverage::coverage(&part1::parse_input(&input()))),
+        Mode::Depth(k) => {
+            coverage::render_depth(&coverage::depth(&part1::parse_input(&input()), k))
+        }
+        Mode::Generate(lines, contained, overlapping, seed) => {
+            if contained > overlapping || overlapping > lines {
+                usage();
+            }
+            let mut rng = Rng::new(seed);
+            generate(&mut rng, lines, SECTIONS, contained, overlapping)
+        }
+    };
+    for line in output {
+        println!("{}", line);
+    }
+}
@@ -1,28 +1,6 @@
-use std::io::{self, BufRead};
+use crate::range::Range;
 
-struct Range {
-    start: i32,
-    end: i32,
-}
-
-impl Range {
-    fn new(rangestr: &str) -> Range {
-        let s: Vec<String> = String::from(rangestr)
-            .split('-')
-            .map(|x| x.to_string())
-            .collect();
-        assert_eq!(s.len(), 2);
-        let a = s[0].parse::<i32>().unwrap();
-        let b = s[1].parse::<i32>().unwrap();
-        Range { start: a, end: b }
-    }
-
-    fn contains(&self, other: &Range) -> bool {
-        self.start <= other.start && self.end >= other.end
-    }
-}
-
-fn parse_input(lines: &Vec<String>) -> Vec<(Range, Range)> {
+pub fn parse_input(lines: &[String]) -> Vec<(Range, Range)> {
     let mut pairs = vec![];
     for line in lines {
         let s: Vec<&str> = line.split(',').collect();
@@ -31,7 +9,7 @@ fn parse_input(lines: &Vec<String>) -> Vec<(Range, Range)> {
     pairs
 }
 
-fn solution(pairs: Vec<(Range, Range)>) -> u32 {
+pub fn solution(pairs: Vec<(Range, Range)>) -> u32 {
     let mut count = 0;
     for pair in pairs {
         let (a, b) = pair;
@@ -42,10 +20,8 @@ fn solution(pairs: Vec<(Range, Range)>) -> u32 {
     count
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines = lines.map(|line| line.unwrap()).collect();
-    println!("{:?}", solution(parse_input(&lines)));
+pub fn solve(lines: &[String]) -> u32 {
+    solution(parse_input(lines))
 }
 
 #[cfg(test)]
@@ -0,0 +1,240 @@
+use std::{
+    cmp::{max, min},
+    fmt,
+};
+
+/// An inclusive range of sections. A range with `start > end` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Range {
+    pub start: i32,
+    pub end: i32,
+}
+
+impl Range {
+    pub fn new(rangestr: &str) -> Range {
+        let s: Vec<String> = String::from(rangestr)
+            .split('-')
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(s.len(), 2);
+        let a = s[0].parse::<i32>().unwrap();
+        let b = s[1].parse::<i32>().unwrap();
+        Range { start: a, end: b }
+    }
+
+    pub fn len(&self) -> i32 {
+        max(self.end - self.start + 1, 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    pub fn contains(&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.start <= other.end && self.end >= other.start
+    }
+
+    pub fn intersection(&self, other: &Range) -> Option<Range> {
+        let range = Range {
+            start: max(self.start, other.start),
+            end: min(self.end, other.end),
+        };
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// The two ranges as one, if they overlap or touch.
+    pub fn union(&self, other: &Range) -> Option<Range> {
+        if self.start > other.end + 1 || other.start > self.end + 1 {
+            return None;
+        }
+        Some(Range {
+            start: min(self.start, other.start),
+            end: max(self.end, other.end),
+        })
+    }
+
+    /// What's left of this range without `other`, up to two pieces.
+    pub fn difference(&self, other: &Range) -> Vec<Range> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        [
+            Range {
+                start: self.start,
+                end: other.start - 1,
+            },
+            Range {
+                start: other.end + 1,
+                end: self.end,
+            },
+        ]
+        .into_iter()
+        .filter(|range| !range.is_empty())
+        .collect()
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Sorted ranges with no overlaps and no gaps of zero sections between them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// The number of sections in the set.
+    pub fn len(&self) -> i32 {
+        self.ranges.iter().map(|range| range.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, section: i32) -> bool {
+        let i = self.ranges.partition_point(|range| range.end < section);
+        i < self.ranges.len() && self.ranges[i].start <= section
+    }
+
+    pub fn insert(&mut self, range: Range) {
+        if range.is_empty() {
+            return;
+        }
+        // the ranges that merge with the new one are all next to each other
+        let from = self.ranges.partition_point(|r| r.end + 1 < range.start);
+        let to = self.ranges.partition_point(|r| r.start <= range.end + 1);
+        let merged = self.ranges[from..to]
+            .iter()
+            .fold(range, |merged, r| merged.union(r).unwrap());
+        self.ranges.splice(from..to, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = self.clone();
+        for &range in other.ranges.iter() {
+            set.insert(range);
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = vec![];
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            ranges.extend(a.intersection(&b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        for range in self.ranges.iter() {
+            let mut pieces = vec![*range];
+            for cut in other.ranges.iter().filter(|cut| cut.overlaps(range)) {
+                pieces = pieces
+                    .iter()
+                    .flat_map(|piece| piece.difference(cut))
+                    .collect();
+            }
+            ranges.extend(pieces);
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The sections between the first and the last one that aren't in the set.
+    pub fn gaps(&self) -> IntervalSet {
+        let ranges = self
+            .ranges
+            .windows(2)
+            .map(|pair| Range {
+                start: pair[0].end + 1,
+                end: pair[1].start - 1,
+            })
+            .collect();
+        IntervalSet { ranges }
+    }
+}
+
+impl FromIterator<Range> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[&str]) -> IntervalSet {
+        ranges.iter().map(|range| Range::new(range)).collect()
+    }
+
+    #[test]
+    fn test_range() {
+        let a = Range::new("5-15");
+        assert_eq!(a.len(), 11);
+        assert_eq!(Range::new("3-2").len(), 0);
+        assert_eq!(
+            a.intersection(&Range::new("10-25")),
+            Some(Range::new("10-15"))
+        );
+        assert_eq!(a.intersection(&Range::new("16-25")), None);
+        assert_eq!(a.union(&Range::new("16-25")), Some(Range::new("5-25")));
+        assert_eq!(a.union(&Range::new("17-25")), None);
+        assert_eq!(
+            a.difference(&Range::new("7-8")),
+            vec![Range::new("5-6"), Range::new("9-15")]
+        );
+        assert_eq!(a.difference(&Range::new("1-10")), vec![Range::new("11-15")]);
+        assert_eq!(a.difference(&Range::new("1-20")), vec![]);
+    }
+
+    #[test]
+    fn test_insert() {
+        let s = set(&["10-12", "1-2", "5-6", "3-3", "20-30", "8-25"]);
+        assert_eq!(
+            s.ranges(),
+            &[Range::new("1-3"), Range::new("5-6"), Range::new("8-30")]
+        );
+        assert_eq!(s.len(), 28);
+        assert!(s.contains(8) && !s.contains(7) && !s.contains(31));
+        assert_eq!(s.gaps(), set(&["4-4", "7-7"]));
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&["1-10", "20-30"]);
+        let b = set(&["5-25"]);
+        assert_eq!(a.union(&b), set(&["1-30"]));
+        assert_eq!(a.intersection(&b), set(&["5-10", "20-25"]));
+        assert_eq!(a.difference(&b), set(&["1-4", "26-30"]));
+        assert_eq!(b.difference(&a), set(&["11-19"]));
+    }
+}
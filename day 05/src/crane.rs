@@ -0,0 +1,113 @@
+/// How a crane puts down the crates it took off the top of a stack.
+pub trait Crane {
+    fn name(&self) -> String;
+
+    /// Puts `crates` (bottom one first, as they were on their stack) on top
+    /// of `to`.
+    fn drop(&self, crates: Vec<char>, to: &mut Vec<char>);
+
+    /// Undoes `drop`: given the crates as they landed, returns them the way
+    /// they were on their stack.
+    fn pick_up(&self, landed: Vec<char>) -> Vec<char>;
+}
+
+/// Moves one crate at a time, so they end up in reverse.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_string()
+    }
+
+    fn drop(&self, crates: Vec<char>, to: &mut Vec<char>) {
+        to.extend(crates.into_iter().rev());
+    }
+
+    fn pick_up(&self, mut landed: Vec<char>) -> Vec<char> {
+        landed.reverse();
+        landed
+    }
+}
+
+/// Moves all the crates at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_string()
+    }
+
+    fn drop(&self, mut crates: Vec<char>, to: &mut Vec<char>) {
+        to.append(&mut crates);
+    }
+
+    fn pick_up(&self, landed: Vec<char>) -> Vec<char> {
+        landed
+    }
+}
+
+/// Lifts at most `capacity` crates per trip, keeping the order within a trip.
+pub struct Limited {
+    pub capacity: usize,
+}
+
+impl Crane for Limited {
+    fn name(&self) -> String {
+        format!("crane lifting {} crates", self.capacity)
+    }
+
+    fn drop(&self, crates: Vec<char>, to: &mut Vec<char>) {
+        // trips take the top crates first
+        for trip in crates.rchunks(self.capacity.max(1)) {
+            to.extend_from_slice(trip);
+        }
+    }
+
+    fn pick_up(&self, landed: Vec<char>) -> Vec<char> {
+        // every trip but the last one was full
+        let trips: Vec<&[char]> = landed.chunks(self.capacity.max(1)).collect();
+        trips.into_iter().rev().flatten().copied().collect()
+    }
+}
+
+/// `9000`, `9001` or the capacity of a limited crane.
+pub fn by_name(name: &str) -> Option<Box<dyn Crane>> {
+    match name {
+        "9000" => Some(Box::new(CrateMover9000)),
+        "9001" => Some(Box::new(CrateMover9001)),
+        _ => match name.parse() {
+            Ok(capacity) if capacity > 0 => Some(Box::new(Limited { capacity })),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dropped(crane: &dyn Crane, crates: &str) -> String {
+        let mut to = vec!['A'];
+        crane.drop(crates.chars().collect(), &mut to);
+        to.into_iter().collect()
+    }
+
+    #[test]
+    fn test_cranes() {
+        assert_eq!(dropped(&CrateMover9000, "BCDEF"), "AFEDCB");
+        assert_eq!(dropped(&CrateMover9001, "BCDEF"), "ABCDEF");
+        assert_eq!(dropped(&Limited { capacity: 2 }, "BCDEF"), "AEFCDB");
+        assert_eq!(dropped(&Limited { capacity: 1 }, "BCDEF"), "AFEDCB");
+        assert_eq!(dropped(&Limited { capacity: 5 }, "BCDEF"), "ABCDEF");
+        for crane in [
+            &CrateMover9000 as &dyn Crane,
+            &CrateMover9001,
+            &Limited { capacity: 2 },
+        ] {
+            let landed: Vec<char> = dropped(crane, "BCDEF").chars().skip(1).collect();
+            assert_eq!(crane.pick_up(landed), "BCDEF".chars().collect::<Vec<_>>());
+        }
+        assert_eq!(by_name("3").unwrap().name(), "crane lifting 3 crates");
+        assert!(by_name("0").is_none());
+    }
+}
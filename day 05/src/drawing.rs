@@ -0,0 +1,123 @@
+use crate::{
+    crane::Crane,
+    plan::{Plan, PlanError},
+};
+
+/// Draws the stacks the way the puzzle does, `[X]` per crate with the stack
+/// numbers underneath, every line as wide as the footer.
+pub fn render(stacks: &[Vec<char>]) -> Vec<String> {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|y| {
+            let cells: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(y) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            cells.join(" ")
+        })
+        .collect();
+    let footer: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+    lines.push(footer.join(" "));
+    lines
+}
+
+/// The whole puzzle input: the drawing, a blank line and the moves.
+pub fn render_plan(plan: &Plan) -> Vec<String> {
+    let mut lines = render(&plan.stacks);
+    lines.push(String::new());
+    lines.extend(plan.instructions.iter().map(|m| m.to_string()));
+    lines
+}
+
+/// The drawing before the first move and after every one of them, unless a
+/// move can't be made.
+pub fn replay(plan: &mut Plan, crane: &dyn Crane) -> Result<Vec<String>, PlanError> {
+    let mut lines = render(&plan.stacks);
+    for index in 0..plan.instructions.len() {
+        plan.check(index)?;
+        plan.step(crane, index);
+        lines.push(String::new());
+        lines.push(plan.instructions[index].to_string());
+        lines.extend(render(&plan.stacks));
+    }
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::rng::Rng;
+
+    use super::*;
+    use crate::{crane::CrateMover9000, plan::parse_input};
+
+    fn example() -> Vec<String> {
+        [
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ]
+        .iter()
+        .map(|x| x.to_string())
+        .collect()
+    }
+
+    #[test]
+    fn test_render() {
+        let lines = example();
+        assert_eq!(render_plan(&parse_input(&lines).unwrap()), lines);
+    }
+
+    #[test]
+    fn test_replay() {
+        let replay = replay(&mut parse_input(&example()).unwrap(), &CrateMover9000).unwrap();
+        assert_eq!(replay[..4], example()[..4]);
+        assert_eq!(
+            replay[replay.len() - 6..],
+            [
+                "move 1 from 1 to 2",
+                "        [Z]",
+                "        [N]",
+                "        [D]",
+                "[C] [M] [P]",
+                " 1   2   3 ",
+            ]
+        );
+
+        let mut lines = example();
+        lines.push("move 4 from 2 to 1".to_string());
+        let error = super::replay(&mut parse_input(&lines).unwrap(), &CrateMover9000).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "instruction 4 (line 10): can't move 4 crates off stack 2, it has 1"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(5);
+        for _ in 0..200 {
+            let stacks: Vec<Vec<char>> = (0..rng.between(1, 9))
+                .map(|_| {
+                    (0..rng.between(0, 12))
+                        .map(|_| (b'A' + rng.below(26) as u8) as char)
+                        .collect()
+                })
+                .collect();
+            let drawing = render(&stacks);
+            let plan = parse_input(&drawing).unwrap();
+            assert_eq!(plan.stacks, stacks);
+            assert_eq!(render(&plan.stacks), drawing);
+        }
+        assert_eq!(parse_input(&[]).unwrap().stacks, Vec::<Vec<char>>::new());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod crane;
+pub mod drawing;
+pub mod part1;
+pub mod part2;
+pub mod plan;
+pub mod reverse;
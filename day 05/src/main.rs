@@ -0,0 +1,107 @@
+use std::{
+    env,
+    io::{self, BufRead},
+    process,
+};
+
+use day05::{
+    crane::{self, Crane, CrateMover9000, CrateMover9001},
+    drawing,
+    plan::{parse_input, Plan},
+    reverse::reverse,
+};
+
+fn usage() -> ! {
+    eprintln!("usage: day05 [--lenient] [9000|9001|<crates per trip>] < input.txt");
+    eprintln!("       day05 --replay [9000|9001|<crates per trip>] < input.txt");
+    eprintln!("       day05 --reverse [9000|9001|<crates per trip>] < final.txt");
+    process::exit(2);
+}
+
+enum Mode {
+    // both parts when no crane is given
+    Run(Vec<Box<dyn Crane>>),
+    Lenient(Vec<Box<dyn Crane>>),
+    Replay(Box<dyn Crane>),
+    // the input's drawing is the final one
+    Reverse(Box<dyn Crane>),
+}
+
+// stops at the first move that can't be made
+fn run(plan: &mut Plan, crane: &dyn Crane) {
+    match plan.try_run(crane) {
+        Ok(tops) => println!("{}", tops),
+        Err(error) => {
+            eprintln!("{}: {}", crane.name(), error);
+            process::exit(1);
+        }
+    }
+}
+
+// skips the moves that can't be made and reports them
+fn run_lenient(plan: &mut Plan, crane: &dyn Crane) {
+    let (tops, errors) = plan.run_lenient(crane);
+    for error in errors {
+        eprintln!("{}: {}", crane.name(), error);
+    }
+    println!("{}", tops);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    let crane = |name: &str| crane::by_name(name).unwrap_or_else(|| usage());
+    let parts =
+        || -> Vec<Box<dyn Crane>> { vec![Box::new(CrateMover9000), Box::new(CrateMover9001)] };
+    let mode = match args[..] {
+        [] => Mode::Run(parts()),
+        ["--lenient"] => Mode::Lenient(parts()),
+        ["--lenient", name] => Mode::Lenient(vec![crane(name)]),
+        ["--replay"] => Mode::Replay(Box::new(CrateMover9000)),
+        ["--replay", name] => Mode::Replay(crane(name)),
+        ["--reverse"] => Mode::Reverse(Box::new(CrateMover9000)),
+        ["--reverse", name] => Mode::Reverse(crane(name)),
+        [name] => Mode::Run(vec![crane(name)]),
+        _ => usage(),
+    };
+    let lines = io::stdin().lock().lines();
+    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
+    let plan = parse_input(&lines).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    match mode {
+        Mode::Run(cranes) => {
+            for crane in cranes {
+                run(&mut plan.clone(), &*crane);
+            }
+        }
+        Mode::Lenient(cranes) => {
+            for crane in cranes {
+                run_lenient(&mut plan.clone(), &*crane);
+            }
+        }
+        Mode::Replay(crane) => match drawing::replay(&mut plan.clone(), &*crane) {
+            Ok(lines) => {
+                for line in lines {
+                    println!("{}", line);
+                }
+            }
+            Err(error) => {
+                eprintln!("{}: {}", crane.name(), error);
+                process::exit(1);
+            }
+        },
+        Mode::Reverse(crane) => match reverse(&plan, &*crane) {
+            Ok(start) => {
+                for line in drawing::render_plan(&start) {
+                    println!("{}", line);
+                }
+            }
+            Err(error) => {
+                eprintln!("{}: {}", crane.name(), error);
+                process::exit(1);
+            }
+        },
+    }
+}
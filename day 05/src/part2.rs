@@ -0,0 +1,101 @@
+use crate::{
+    crane::CrateMover9001,
+    plan::{parse_input, Plan},
+};
+
+pub fn solution(plan: &mut Plan) -> String {
+    plan.run(&CrateMover9001);
+    plan.tops()
+}
+
+pub fn solve(lines: &[String]) -> String {
+    solution(&mut parse_input(lines).unwrap_or_else(|e| panic!("{}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::Move;
+
+    #[test]
+    fn test_parse_stacks() {
+        let lines = [
+            "    [D]",
+            "[N] [C]",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ];
+        let lines: Vec<String> = lines.iter().map(|x| x.to_string()).collect();
+        let plan = parse_input(&lines).unwrap();
+        assert_eq!(
+            plan.stacks,
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+        );
+    }
+
+    #[test]
+    fn test_parse_instructions() {
+        let lines = [
+            "    [D]",
+            "[N] [C]",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ];
+        let lines: Vec<String> = lines.iter().map(|x| x.to_string()).collect();
+        let plan = parse_input(&lines).unwrap();
+        assert_eq!(
+            plan.instructions,
+            vec![
+                Move {
+                    count: 1,
+                    from: 2,
+                    to: 1
+                },
+                Move {
+                    count: 3,
+                    from: 1,
+                    to: 3
+                },
+                Move {
+                    count: 2,
+                    from: 2,
+                    to: 1
+                },
+                Move {
+                    count: 1,
+                    from: 1,
+                    to: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let lines = [
+            "    [D]",
+            "[N] [C]",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ];
+        let lines: Vec<String> = lines.iter().map(|x| x.to_string()).collect();
+        let mut plan = parse_input(&lines).unwrap();
+        assert_eq!(solution(&mut plan), "MCD");
+        println!("stacks: {:?}", &plan.stacks);
+    }
+}
@@ -0,0 +1,336 @@
+use std::fmt;
+
+use common::parse::{blocks, int, ParseError};
+use nom::{
+    bytes::complete::tag,
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::crane::Crane;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    // bottom crate first
+    pub stacks: Vec<Vec<char>>,
+    pub instructions: Vec<Move>,
+    // the line of the input each instruction is on, from 1
+    pub lines: Vec<usize>,
+}
+
+/// Why a plan can't be carried out. Instructions are indexed from 0, lines
+/// and stacks numbered from 1 like in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    NoSuchStack {
+        index: usize,
+        line: usize,
+        stack: usize,
+    },
+    NotEnoughCrates {
+        index: usize,
+        line: usize,
+        stack: usize,
+        wanted: usize,
+        has: usize,
+    },
+    EmptyStack {
+        stack: usize,
+    },
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::NoSuchStack { index, line, stack } => write!(
+                f,
+                "instruction {} (line {}): there is no stack {}",
+                index, line, stack
+            ),
+            PlanError::NotEnoughCrates {
+                index,
+                line,
+                stack,
+                wanted,
+                has,
+            } => write!(
+                f,
+                "instruction {} (line {}): can't move {} crates off stack {}, it has {}",
+                index, line, wanted, stack, has
+            ),
+            PlanError::EmptyStack { stack } => {
+                write!(f, "stack {} is empty at the end", stack)
+            }
+        }
+    }
+}
+
+pub fn instruction(input: &str) -> IResult<&str, Move> {
+    map(
+        tuple((
+            preceded(tag("move "), int),
+            preceded(tag(" from "), int),
+            preceded(tag(" to "), int),
+        )),
+        |(count, from, to)| Move { count, from, to },
+    )(input)
+}
+
+/// The stacks and moves, unless a move can't be read.
+pub fn parse_input(lines: &[String]) -> Result<Plan, ParseError> {
+    let blocks = blocks(lines);
+    let mut rows: Vec<String> = vec![];
+    // the last line of the drawing is the ` 1   2   3 ` footer, which also
+    // numbers the stacks that are empty
+    let drawing = blocks.first().map_or(&[][..], |block| block.lines);
+    let (mut stack_count, drawing) = match drawing.split_last() {
+        Some((footer, drawing)) => (footer.split_whitespace().count(), drawing),
+        None => (0, drawing),
+    };
+    for line in drawing {
+        let mut row = "".to_string();
+        let mut i = 1;
+        let line: Vec<char> = line.chars().collect();
+        while i < line.len() {
+            row.push(line[i]);
+            i += 4;
+            stack_count = std::cmp::max(stack_count, row.len());
+        }
+        rows.push(row);
+    }
+
+    // transpose rows into stacks
+    let mut stacks: Vec<Vec<char>> = vec![vec![]; stack_count];
+    for y in 0..rows.len() {
+        let row: Vec<char> = rows[rows.len() - 1 - y].chars().collect();
+        for x in 0..stack_count {
+            if x >= row.len() {
+                continue;
+            }
+            let c = row[x];
+            if c != ' ' {
+                stacks[x].push(c);
+            }
+        }
+    }
+
+    let (instructions, lines) = match blocks.get(1) {
+        Some(block) => (
+            block.parse_lines(instruction)?,
+            (0..block.lines.len())
+                .map(|i| block.start + i + 1)
+                .collect(),
+        ),
+        None => (vec![], vec![]),
+    };
+
+    Ok(Plan {
+        stacks,
+        instructions,
+        lines,
+    })
+}
+
+impl Plan {
+    /// Runs instruction `index` with `crane`.
+    pub fn step(&mut self, crane: &dyn Crane, index: usize) {
+        let instruction = &self.instructions[index];
+        let from = &mut self.stacks[instruction.from - 1];
+        let crates = from.split_off(from.len() - instruction.count);
+        crane.drop(crates, &mut self.stacks[instruction.to - 1]);
+    }
+
+    pub fn run(&mut self, crane: &dyn Crane) {
+        for index in 0..self.instructions.len() {
+            self.step(crane, index);
+        }
+    }
+
+    /// Whether instruction `index` can run on the stacks as they are now.
+    pub fn check(&self, index: usize) -> Result<(), PlanError> {
+        self.check_lift(index, self.instructions[index].from)
+    }
+
+    /// Whether both stacks of instruction `index` exist and `stack` (one of
+    /// them) has the crates to lift.
+    pub fn check_lift(&self, index: usize, stack: usize) -> Result<(), PlanError> {
+        let instruction = &self.instructions[index];
+        let line = self.lines[index];
+        for stack in [instruction.from, instruction.to] {
+            if stack == 0 || stack > self.stacks.len() {
+                return Err(PlanError::NoSuchStack { index, line, stack });
+            }
+        }
+        let has = self.stacks[stack - 1].len();
+        if has < instruction.count {
+            return Err(PlanError::NotEnoughCrates {
+                index,
+                line,
+                stack,
+                wanted: instruction.count,
+                has,
+            });
+        }
+        Ok(())
+    }
+
+    /// Runs every instruction, stopping at the first one that can't run, and
+    /// returns the top crates.
+    pub fn try_run(&mut self, crane: &dyn Crane) -> Result<String, PlanError> {
+        for index in 0..self.instructions.len() {
+            self.check(index)?;
+            self.step(crane, index);
+        }
+        self.try_tops()
+    }
+
+    /// Runs every instruction that can run and skips the others. Empty stacks
+    /// show up as spaces in the top crates.
+    pub fn run_lenient(&mut self, crane: &dyn Crane) -> (String, Vec<PlanError>) {
+        let mut errors = vec![];
+        for index in 0..self.instructions.len() {
+            match self.check(index) {
+                Ok(()) => self.step(crane, index),
+                Err(error) => errors.push(error),
+            }
+        }
+        for (i, stack) in self.stacks.iter().enumerate() {
+            if stack.is_empty() {
+                errors.push(PlanError::EmptyStack { stack: i + 1 });
+            }
+        }
+        (self.tops(), errors)
+    }
+
+    pub fn try_tops(&self) -> Result<String, PlanError> {
+        self.stacks
+            .iter()
+            .enumerate()
+            .map(|(i, stack)| stack.last().ok_or(PlanError::EmptyStack { stack: i + 1 }))
+            .collect()
+    }
+
+    /// The crate on top of each stack, a space for an empty one.
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane::{CrateMover9000, CrateMover9001};
+
+    fn plan(moves: &[&str]) -> Plan {
+        let mut lines = vec![
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+        ];
+        lines.extend(moves);
+        parse_input(&lines.iter().map(|x| x.to_string()).collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = ["[A]", " 1 ", "", "move 1 from 1 to 1", "move x from 1 to 1"];
+        let error = parse_input(&lines.map(|x| x.to_string())).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 6));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = plan(&["move 1 from 2 to 1", "move 1 from 4 to 1"])
+            .try_run(&CrateMover9000)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            PlanError::NoSuchStack {
+                index: 1,
+                line: 7,
+                stack: 4
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "instruction 1 (line 7): there is no stack 4"
+        );
+
+        let error = plan(&["move 3 from 3 to 0"])
+            .try_run(&CrateMover9000)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            PlanError::NoSuchStack {
+                index: 0,
+                line: 6,
+                stack: 0
+            }
+        );
+
+        let error = plan(&["move 4 from 2 to 1"])
+            .try_run(&CrateMover9001)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            PlanError::NotEnoughCrates {
+                index: 0,
+                line: 6,
+                stack: 2,
+                wanted: 4,
+                has: 3
+            }
+        );
+
+        let error = plan(&["move 1 from 3 to 1"])
+            .try_run(&CrateMover9001)
+            .unwrap_err();
+        assert_eq!(error, PlanError::EmptyStack { stack: 3 });
+        assert_eq!(
+            plan(&["move 1 from 3 to 3"]).try_run(&CrateMover9001),
+            Ok("NDP".to_string())
+        );
+    }
+
+    #[test]
+    fn test_lenient() {
+        let (tops, errors) = plan(&[
+            "move 1 from 3 to 1",
+            "move 5 from 1 to 2",
+            "move 1 from 9 to 1",
+            "move 3 from 2 to 3",
+        ])
+        .run_lenient(&CrateMover9000);
+        assert_eq!(tops, "P M");
+        let lines: Vec<usize> = errors
+            .iter()
+            .filter_map(|error| match error {
+                PlanError::NoSuchStack { line, .. } | PlanError::NotEnoughCrates { line, .. } => {
+                    Some(*line)
+                }
+                PlanError::EmptyStack { .. } => None,
+            })
+            .collect();
+        assert_eq!(lines, vec![7, 8]);
+        assert_eq!(errors[2], PlanError::EmptyStack { stack: 2 });
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    crane::Crane,
+    plan::{Plan, PlanError},
+};
+
+impl Plan {
+    /// Undoes instruction `index`, taking its crates back off the stack they
+    /// were moved to.
+    pub fn unstep(&mut self, crane: &dyn Crane, index: usize) {
+        let instruction = &self.instructions[index];
+        let to = &mut self.stacks[instruction.to - 1];
+        let landed = to.split_off(to.len() - instruction.count);
+        self.stacks[instruction.from - 1].extend(crane.pick_up(landed));
+    }
+
+    /// Takes the stacks from the end of the plan back to the start, undoing
+    /// the instructions from the last one.
+    pub fn try_unrun(&mut self, crane: &dyn Crane) -> Result<(), PlanError> {
+        for index in (0..self.instructions.len()).rev() {
+            self.check_lift(index, self.instructions[index].to)?;
+            self.unstep(crane, index);
+        }
+        Ok(())
+    }
+}
+
+/// The plan that ends up with the stacks of `plan` once `crane` has carried
+/// out its instructions.
+pub fn reverse(plan: &Plan, crane: &dyn Crane) -> Result<Plan, PlanError> {
+    let mut plan = plan.clone();
+    plan.try_unrun(crane)?;
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::rng::Rng;
+
+    use super::*;
+    use crate::{
+        crane::{CrateMover9000, CrateMover9001, Limited},
+        drawing::render_plan,
+        plan::{parse_input, Move},
+    };
+
+    fn cranes() -> Vec<Box<dyn Crane>> {
+        vec![
+            Box::new(CrateMover9000),
+            Box::new(CrateMover9001),
+            Box::new(Limited { capacity: 2 }),
+            Box::new(Limited { capacity: 3 }),
+        ]
+    }
+
+    // a plan whose moves can all be carried out, starting from random stacks
+    fn random_plan(rng: &mut Rng, crane: &dyn Crane) -> Plan {
+        let stack_count = rng.between(1, 9) as usize;
+        let stacks: Vec<Vec<char>> = (0..stack_count)
+            .map(|_| {
+                (0..rng.between(0, 8))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        let mut plan = Plan {
+            stacks,
+            instructions: vec![],
+            lines: vec![],
+        };
+        let mut stacks = plan.stacks.clone();
+        for _ in 0..rng.between(0, 30) {
+            let from = rng.index(stack_count);
+            if stacks[from].is_empty() {
+                continue;
+            }
+            let count = rng.between(1, stacks[from].len() as i64) as usize;
+            let to = rng.index(stack_count);
+            let left = stacks[from].len() - count;
+            let crates = stacks[from].split_off(left);
+            crane.drop(crates, &mut stacks[to]);
+            plan.lines.push(plan.instructions.len() + 1);
+            plan.instructions.push(Move {
+                count,
+                from: from + 1,
+                to: to + 1,
+            });
+        }
+        plan
+    }
+
+    #[test]
+    fn test_example() {
+        let lines: Vec<String> = [
+            "        [Z]",
+            "        [N]",
+            "        [D]",
+            "[C] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ]
+        .iter()
+        .map(|x| x.to_string())
+        .collect();
+        let start = reverse(&parse_input(&lines).unwrap(), &CrateMover9000).unwrap();
+        assert_eq!(
+            render_plan(&start)[..4],
+            ["    [D]    ", "[N] [C]    ", "[Z] [M] [P]", " 1   2   3 "]
+        );
+
+        // the stacks can't have come from these moves with the other crane
+        let start = reverse(&parse_input(&lines).unwrap(), &CrateMover9001).unwrap();
+        assert_ne!(
+            start.stacks,
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(44);
+        for crane in cranes() {
+            for _ in 0..200 {
+                let start = random_plan(&mut rng, &*crane);
+                let mut end = start.clone();
+                end.run(&*crane);
+
+                // reverse(forward(x)) == x
+                let reversed = reverse(&end, &*crane).unwrap();
+                assert_eq!(reversed, start);
+
+                // forward(reverse(x)) == x
+                let mut forward = reversed.clone();
+                forward.run(&*crane);
+                assert_eq!(forward.stacks, end.stacks);
+            }
+        }
+    }
+
+    #[test]
+    fn test_impossible() {
+        let mut plan =
+            parse_input(&["[A]    ", " 1   2 ", "", "move 1 from 1 to 2"].map(|x| x.to_string()))
+                .unwrap();
+        assert_eq!(
+            plan.try_unrun(&CrateMover9000),
+            Err(PlanError::NotEnoughCrates {
+                index: 0,
+                line: 4,
+                stack: 2,
+                wanted: 1,
+                has: 0
+            })
+        );
+    }
+}
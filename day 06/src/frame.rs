@@ -0,0 +1,326 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use crate::marker::MarkerDetector;
+
+/// The bytes between one message marker and the next, and where they start in
+/// the signal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub offset: usize,
+    pub payload: Vec<u8>,
+}
+
+/// A byte that isn't a letter, and the frames the same chunk closed before
+/// it, which would otherwise be lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    pub offset: usize,
+    pub byte: u8,
+    pub frames: Vec<Frame>,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "offset {}: not a letter: {:?}",
+            self.offset, self.byte as char
+        )
+    }
+}
+
+/// Splits a signal into frames as it arrives, in chunks of any size. Whatever
+/// comes before the first marker is dropped. After each marker the search
+/// starts over, so the next marker is made of payload bytes only and never
+/// overlaps the one before.
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    detector: MarkerDetector,
+    offset: usize,
+    // where the open frame's payload starts, once there's a marker
+    start: Option<usize>,
+    // the open frame so far, including what may turn out to be the next marker
+    pending: Vec<u8>,
+}
+
+impl Decoder {
+    pub fn new(length: usize) -> Self {
+        Self {
+            detector: MarkerDetector::new(length),
+            offset: 0,
+            start: None,
+            pending: Vec::new(),
+        }
+    }
+
+    /// How many bytes have been fed so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Takes the next chunk and returns the frames it closes. A byte that
+    /// isn't a letter stops it there, and is left out of `offset`.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<Frame>, DecodeError> {
+        let mut frames = Vec::new();
+        for &byte in chunk {
+            let Ok(marker) = self.detector.push(byte) else {
+                return Err(DecodeError {
+                    offset: self.offset,
+                    byte,
+                    frames,
+                });
+            };
+            self.offset += 1;
+            if self.start.is_some() {
+                self.pending.push(byte);
+            }
+            if !marker {
+                continue;
+            }
+            if let Some(start) = self.start {
+                let length = self.pending.len() - self.detector.length();
+                self.pending.truncate(length);
+                frames.push(Frame {
+                    offset: start,
+                    payload: std::mem::take(&mut self.pending),
+                });
+            }
+            self.start = Some(self.offset);
+            self.detector = MarkerDetector::new(self.detector.length());
+        }
+        Ok(frames)
+    }
+
+    /// The last frame, which runs to the end of the signal.
+    pub fn finish(self) -> Option<Frame> {
+        self.start.map(|offset| Frame {
+            offset,
+            payload: self.pending,
+        })
+    }
+}
+
+/// Every frame of a signal that's all in memory, as slices of it.
+pub fn split(signal: &str, length: usize) -> Result<Vec<(usize, &str)>, DecodeError> {
+    let mut decoder = Decoder::new(length);
+    let mut frames = decoder.feed(signal.as_bytes())?;
+    frames.extend(decoder.finish());
+    Ok(frames
+        .iter()
+        .map(|frame| {
+            let end = frame.offset + frame.payload.len();
+            (frame.offset, &signal[frame.offset..end])
+        })
+        .collect())
+}
+
+/// Why `decode` stopped before the end of the signal, with the frames it had
+/// closed by then.
+#[derive(Debug)]
+pub struct Partial {
+    pub frames: Vec<Frame>,
+    pub error: io::Error,
+}
+
+impl fmt::Display for Partial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for Partial {}
+
+/// Every frame of a signal read a chunk at a time, skipping whitespace the way
+/// `markers` does. Offsets, in the frames and in errors, count letters only,
+/// so they are off from the raw stream by the whitespace before them.
+pub fn decode<R: Read>(mut reader: R, length: usize) -> Result<Vec<Frame>, Partial> {
+    let mut decoder = Decoder::new(length);
+    let mut frames = Vec::new();
+    let mut buffer = [0; 4096];
+    loop {
+        let n = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(error) => return Err(Partial { frames, error }),
+        };
+        let chunk: Vec<u8> = buffer[..n]
+            .iter()
+            .copied()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .collect();
+        match decoder.feed(&chunk) {
+            Ok(decoded) => frames.extend(decoded),
+            Err(error) => {
+                frames.extend(error.frames.iter().cloned());
+                let error = io::Error::new(io::ErrorKind::InvalidData, error.to_string());
+                return Err(Partial { frames, error });
+            }
+        }
+    }
+    frames.extend(decoder.finish());
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::marker::{START_OF_MESSAGE, START_OF_PACKET};
+
+    // searches each frame for the next marker from scratch
+    fn naive(signal: &str, length: usize) -> Vec<(usize, &str)> {
+        let bytes = signal.as_bytes();
+        let distinct = |w: &[u8]| (1..w.len()).all(|i| !w[..i].contains(&w[i]));
+        let next = |from: usize| {
+            (from + length..=bytes.len()).find(|&end| distinct(&bytes[end - length..end]))
+        };
+        let mut frames = Vec::new();
+        let mut start = next(0);
+        while let Some(offset) = start {
+            start = next(offset);
+            let end = start.map_or(bytes.len(), |end| end - length);
+            frames.push((offset, &signal[offset..end]));
+        }
+        frames
+    }
+
+    fn chunked(signal: &str, length: usize, sizes: &[usize]) -> Vec<Frame> {
+        let mut decoder = Decoder::new(length);
+        let mut frames = Vec::new();
+        let mut rest = signal.as_bytes();
+        for &size in sizes.iter().cycle() {
+            if rest.is_empty() {
+                break;
+            }
+            let (chunk, tail) = rest.split_at(size.min(rest.len()));
+            frames.extend(decoder.feed(chunk).unwrap());
+            rest = tail;
+        }
+        frames.extend(decoder.finish());
+        frames
+    }
+
+    fn owned(frames: &[(usize, &str)]) -> Vec<Frame> {
+        frames
+            .iter()
+            .map(|&(offset, payload)| Frame {
+                offset,
+                payload: payload.as_bytes().to_vec(),
+            })
+            .collect()
+    }
+
+    const SIGNAL: &str = concat!(
+        "aaaa",
+        "abcdefghijklmn",
+        "aaaa",
+        "abcdefghijklmn",
+        "mmmnnn",
+        "nopqrstuvwxyza",
+        "",
+        "abcdefghijklmn",
+        "qq",
+    );
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            split(SIGNAL, START_OF_MESSAGE).unwrap(),
+            [(18, "aaaa"), (36, "mmmnnn"), (56, ""), (70, "qq")]
+        );
+        assert_eq!(split("abcabc", START_OF_MESSAGE).unwrap(), []);
+        assert_eq!(
+            split("abCd", 2),
+            Err(DecodeError {
+                offset: 2,
+                byte: b'C',
+                frames: vec![]
+            })
+        );
+
+        // the frames before the bad byte still come out
+        let mut decoder = Decoder::new(2);
+        let error = decoder.feed(b"abcaaccdC").unwrap_err();
+        assert_eq!((error.offset, error.byte), (8, b'C'));
+        assert_eq!(error.frames, owned(&[(2, ""), (4, ""), (6, "")]));
+        assert_eq!(decoder.offset(), 8);
+    }
+
+    #[test]
+    fn test_naive() {
+        for signal in [
+            SIGNAL,
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+        ] {
+            for length in [1, 2, START_OF_PACKET, START_OF_MESSAGE] {
+                assert_eq!(split(signal, length).unwrap(), naive(signal, length));
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunks() {
+        let whole = owned(&split(SIGNAL, START_OF_MESSAGE).unwrap());
+        // every chunk size, including ones that cut markers and payloads
+        for size in 1..=SIGNAL.len() {
+            assert_eq!(chunked(SIGNAL, START_OF_MESSAGE, &[size]), whole);
+        }
+        // two cuts anywhere, with empty chunks in between
+        for a in 0..=SIGNAL.len() {
+            for b in a..=SIGNAL.len() {
+                let sizes = [a, 0, b - a, SIGNAL.len() - b];
+                assert_eq!(chunked(SIGNAL, START_OF_MESSAGE, &sizes), whole);
+            }
+        }
+        assert_eq!(chunked(SIGNAL, START_OF_MESSAGE, &[3, 1, 7, 2]), whole);
+    }
+
+    #[test]
+    fn test_interleaved() {
+        // two signals arriving in turns, each with its own decoder
+        let other = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        let (mut a, mut b) = (
+            Decoder::new(START_OF_MESSAGE),
+            Decoder::new(START_OF_PACKET),
+        );
+        let (mut from_a, mut from_b) = (Vec::new(), Vec::new());
+        let mut chunks_a = SIGNAL.as_bytes().chunks(5);
+        let mut chunks_b = other.as_bytes().chunks(3);
+        loop {
+            let (chunk_a, chunk_b) = (chunks_a.next(), chunks_b.next());
+            if chunk_a.is_none() && chunk_b.is_none() {
+                break;
+            }
+            from_a.extend(a.feed(chunk_a.unwrap_or_default()).unwrap());
+            from_b.extend(b.feed(chunk_b.unwrap_or_default()).unwrap());
+        }
+        assert_eq!(a.offset(), SIGNAL.len());
+        from_a.extend(a.finish());
+        from_b.extend(b.finish());
+        assert_eq!(from_a, owned(&naive(SIGNAL, START_OF_MESSAGE)));
+        assert_eq!(from_b, owned(&naive(other, START_OF_PACKET)));
+    }
+
+    #[test]
+    fn test_decode() {
+        let signal = format!("{}\n", SIGNAL);
+        let frames = decode(signal.as_bytes(), START_OF_MESSAGE).unwrap();
+        assert_eq!(frames, owned(&split(SIGNAL, START_OF_MESSAGE).unwrap()));
+        // whitespace anywhere is skipped, and isn't counted
+        let spaced = "abcd efghijklmnaa\tabcdefghijklmn\r\npp\n";
+        let frames = decode(spaced.as_bytes(), START_OF_MESSAGE).unwrap();
+        assert_eq!(frames, owned(&[(14, "aa"), (30, "pp")]));
+        let error = decode("ab\r\ncD".as_bytes(), 2).unwrap_err();
+        assert_eq!(error.error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "offset 3: not a letter: 'D'");
+
+        // the frames before a bad byte still come out
+        let signal = "abcdefghijklmnaaabcdefghijklmnpp1q";
+        let error = decode(signal.as_bytes(), START_OF_MESSAGE).unwrap_err();
+        assert_eq!(error.frames, owned(&[(14, "aa")]));
+        assert_eq!(error.to_string(), "offset 32: not a letter: '1'");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod frame;
+pub mod marker;
+pub mod part1;
+pub mod part2;
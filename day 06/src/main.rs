@@ -0,0 +1,71 @@
+use std::{env, io, process};
+
+use day06::{
+    frame::{decode, Frame},
+    marker::{markers, start, START_OF_MESSAGE, START_OF_PACKET},
+};
+
+fn usage() -> ! {
+    eprintln!("usage: day06 < input.txt");
+    eprintln!("       day06 --all [<marker length>...] < input.txt");
+    eprintln!("       day06 --frames < input.txt");
+    process::exit(2);
+}
+
+fn fail(error: impl std::fmt::Display) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
+}
+
+// both answers in a single pass over stdin
+fn print_start() {
+    let (packet, message) = start(io::stdin().lock()).unwrap_or_else(|e| fail(e));
+    println!(
+        "{}",
+        packet.unwrap_or_else(|| fail("no start-of-packet marker"))
+    );
+    println!(
+        "{}",
+        message.unwrap_or_else(|| fail("no start-of-message marker"))
+    );
+}
+
+// every marker as it's found, with its length
+fn print_all(lengths: &[usize]) {
+    for marker in markers(io::stdin().lock(), lengths) {
+        let marker = marker.unwrap_or_else(|e| fail(e));
+        println!("{} {}", marker.length, marker.position);
+    }
+}
+
+fn print_frame(frame: Frame) {
+    let payload = String::from_utf8(frame.payload).unwrap();
+    println!("{} {} {}", frame.offset, payload.len(), payload);
+}
+
+// each frame's offset, length and payload, up to anything that's wrong
+fn print_frames() {
+    match decode(io::stdin().lock(), START_OF_MESSAGE) {
+        Ok(frames) => frames.into_iter().for_each(print_frame),
+        Err(partial) => {
+            partial.frames.into_iter().for_each(print_frame);
+            fail(partial.error);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    let length = |arg: &&str| match arg.parse() {
+        Ok(n) if n > 0 => n,
+        _ => usage(),
+    };
+    match args[..] {
+        [] => print_start(),
+        ["--frames"] => print_frames(),
+        ["--all"] => print_all(&[START_OF_PACKET, START_OF_MESSAGE]),
+        ["--all", ref lengths @ ..] => print_all(&lengths.iter().map(length).collect::<Vec<_>>()),
+        _ => usage(),
+    }
+}
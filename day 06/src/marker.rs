@@ -0,0 +1,252 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    io::{self, BufReader, Bytes, Read},
+};
+
+pub const START_OF_PACKET: usize = 4;
+pub const START_OF_MESSAGE: usize = 14;
+
+/// A byte that can't be part of a marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotALetter(pub u8);
+
+impl fmt::Display for NotALetter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not a letter: {:?}", self.0 as char)
+    }
+}
+
+impl std::error::Error for NotALetter {}
+
+// the letter's slot in the counts
+fn letter(byte: u8) -> Result<usize, NotALetter> {
+    if byte.is_ascii_lowercase() {
+        Ok((byte - b'a') as usize)
+    } else {
+        Err(NotALetter(byte))
+    }
+}
+
+/// Finds the windows of `length` all-distinct letters in a stream, one byte at
+/// a time. It keeps a count per letter and how many letters appear more than
+/// once in the window, so each byte costs the same whatever the length.
+#[derive(Debug, Clone)]
+pub struct MarkerDetector {
+    length: usize,
+    counts: [usize; 26],
+    // letters that appear more than once in the window
+    repeated: usize,
+    window: VecDeque<u8>,
+    position: usize,
+}
+
+impl MarkerDetector {
+    pub fn new(length: usize) -> Self {
+        assert!(length > 0, "a marker needs at least one character");
+        Self {
+            length,
+            counts: [0; 26],
+            repeated: 0,
+            window: VecDeque::with_capacity(length),
+            position: 0,
+        }
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// How many characters have been pushed so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Adds the next letter of the stream and tells whether the window that
+    /// ends with it is a marker, in which case `position` is the answer the
+    /// elves are after. Anything but a letter is turned away and leaves the
+    /// detector as it was.
+    pub fn push(&mut self, byte: u8) -> Result<bool, NotALetter> {
+        let slot = letter(byte)?;
+        if self.window.len() == self.length {
+            let first = (self.window.pop_front().unwrap() - b'a') as usize;
+            self.counts[first] -= 1;
+            if self.counts[first] == 1 {
+                self.repeated -= 1;
+            }
+        }
+        self.counts[slot] += 1;
+        if self.counts[slot] == 2 {
+            self.repeated += 1;
+        }
+        self.window.push_back(byte);
+        self.position += 1;
+        Ok(self.window.len() == self.length && self.repeated == 0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    pub length: usize,
+    pub position: usize,
+}
+
+/// Every marker of each length in a stream, in the order they end. Whitespace
+/// is skipped, so a trailing newline doesn't matter.
+pub struct Markers<R> {
+    bytes: Bytes<BufReader<R>>,
+    detectors: Vec<MarkerDetector>,
+    found: VecDeque<Marker>,
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = io::Result<Marker>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.found.is_empty() {
+            let byte = match self.bytes.next()? {
+                Ok(byte) => byte,
+                Err(error) => return Some(Err(error)),
+            };
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+            if let Err(error) = letter(byte) {
+                return Some(Err(io::Error::new(io::ErrorKind::InvalidData, error)));
+            }
+            for detector in &mut self.detectors {
+                if detector.push(byte) == Ok(true) {
+                    self.found.push_back(Marker {
+                        length: detector.length(),
+                        position: detector.position(),
+                    });
+                }
+            }
+        }
+        self.found.pop_front().map(Ok)
+    }
+}
+
+/// Scans `reader` once for the markers of all of `lengths`.
+pub fn markers<R: Read>(reader: R, lengths: &[usize]) -> Markers<R> {
+    Markers {
+        bytes: BufReader::new(reader).bytes(),
+        detectors: lengths.iter().map(|&n| MarkerDetector::new(n)).collect(),
+        found: VecDeque::new(),
+    }
+}
+
+/// Where the first marker of `length` ends in `line`, skipping whitespace
+/// like `markers` does.
+pub fn first(line: &str, length: usize) -> Result<Option<usize>, NotALetter> {
+    let mut detector = MarkerDetector::new(length);
+    for byte in line.bytes().filter(|byte| !byte.is_ascii_whitespace()) {
+        if detector.push(byte)? {
+            return Ok(Some(detector.position()));
+        }
+    }
+    Ok(None)
+}
+
+/// The first start-of-packet and start-of-message markers, in a single pass
+/// that stops as soon as both are known.
+pub fn start<R: Read>(reader: R) -> io::Result<(Option<usize>, Option<usize>)> {
+    let (mut packet, mut message) = (None, None);
+    for marker in markers(reader, &[START_OF_PACKET, START_OF_MESSAGE]) {
+        let marker = marker?;
+        match marker.length {
+            START_OF_PACKET => packet = packet.or(Some(marker.position)),
+            _ => message = message.or(Some(marker.position)),
+        }
+        if packet.is_some() && message.is_some() {
+            break;
+        }
+    }
+    Ok((packet, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    // every window checked on its own
+    fn naive(line: &str, length: usize) -> Vec<usize> {
+        let bytes = line.as_bytes();
+        (length..=bytes.len())
+            .filter(|&end| {
+                let window = &bytes[end - length..end];
+                (1..length).all(|i| !window[..i].contains(&window[i]))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_examples() {
+        for (line, packet, message) in EXAMPLES {
+            assert_eq!(first(line, START_OF_PACKET), Ok(Some(packet)));
+            assert_eq!(first(line, START_OF_MESSAGE), Ok(Some(message)));
+            assert_eq!(
+                start(line.as_bytes()).unwrap(),
+                (Some(packet), Some(message))
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_marker() {
+        for (line, _, _) in EXAMPLES {
+            let found: Vec<Marker> = markers(line.as_bytes(), &[4, 14])
+                .map(|marker| marker.unwrap())
+                .collect();
+            for length in [4, 14] {
+                let positions: Vec<usize> = found
+                    .iter()
+                    .filter(|marker| marker.length == length)
+                    .map(|marker| marker.position)
+                    .collect();
+                assert_eq!(positions, naive(line, length));
+            }
+            // in the order they end
+            assert!(found.windows(2).all(|w| w[0].position <= w[1].position));
+        }
+    }
+
+    #[test]
+    fn test_edges() {
+        // the last window counts, and short inputs don't underflow
+        assert_eq!(first("aaabcd", 4), Ok(Some(6)));
+        assert_eq!(first("abcd", 4), Ok(Some(4)));
+        assert_eq!(first("abc", 4), Ok(None));
+        assert_eq!(first("", 14), Ok(None));
+        assert_eq!(first("abababab", 3), Ok(None));
+        assert_eq!(first("a", 1), Ok(Some(1)));
+        assert_eq!(start("abc\n".as_bytes()).unwrap(), (None, None));
+        assert_eq!(markers("abcd\n".as_bytes(), &[4]).count(), 1);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut found = markers("abC".as_bytes(), &[2]);
+        assert_eq!(found.next().unwrap().unwrap().position, 2);
+        let error = found.next().unwrap().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        // a line read with its \r still on
+        assert_eq!(first("abcd\r", 4), Ok(Some(4)));
+        assert_eq!(first("abc\r", 4), Ok(None));
+        assert_eq!(first("aa-cd", 2), Err(NotALetter(b'-')));
+        let mut detector = MarkerDetector::new(2);
+        assert_eq!(detector.push(b'a'), Ok(false));
+        assert_eq!(detector.push(b'A'), Err(NotALetter(b'A')));
+        assert_eq!(detector.position(), 1);
+        assert_eq!(detector.push(b'b'), Ok(true));
+    }
+}
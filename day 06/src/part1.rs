@@ -0,0 +1,34 @@
+use crate::marker::{first, NotALetter, START_OF_PACKET};
+
+pub fn parse_input(lines: &[String]) -> &str {
+    lines.first().map_or("", |line| line)
+}
+
+pub fn solution(line: &str) -> Result<Option<usize>, NotALetter> {
+    first(line, START_OF_PACKET)
+}
+
+pub fn solve(lines: &[String]) -> usize {
+    solution(parse_input(lines))
+        .unwrap_or_else(|e| panic!("{}", e))
+        .expect("no start-of-packet marker")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solution(parse_input(&["bvwbjplbgvbhsrlpgdmjqwftvncz".to_string()])), Ok(Some(5)));
+        assert_eq!(solution(parse_input(&["nppdvjthqldpwncqszvftbrmjlhg".to_string()])), Ok(Some(6)));
+        assert_eq!(solution(parse_input(&["nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string()])), Ok(Some(10)));
+        assert_eq!(solution(parse_input(&["zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string()])), Ok(Some(11)));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(parse_input(&[]), "");
+        assert_eq!(solution(parse_input(&[])), Ok(None));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::marker::{first, NotALetter, START_OF_MESSAGE};
+
+pub fn parse_input(lines: &[String]) -> &str {
+    lines.first().map_or("", |line| line)
+}
+
+pub fn solution(line: &str) -> Result<Option<usize>, NotALetter> {
+    first(line, START_OF_MESSAGE)
+}
+
+pub fn solve(lines: &[String]) -> usize {
+    solution(parse_input(lines))
+        .unwrap_or_else(|e| panic!("{}", e))
+        .expect("no start-of-message marker")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solution(parse_input(&["mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string()])), Ok(Some(19)));
+        assert_eq!(solution(parse_input(&["bvwbjplbgvbhsrlpgdmjqwftvncz".to_string()])), Ok(Some(23)));
+        assert_eq!(solution(parse_input(&["nppdvjthqldpwncqszvftbrmjlhg".to_string()])), Ok(Some(23)));
+        assert_eq!(solution(parse_input(&["nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string()])), Ok(Some(29)));
+        assert_eq!(solution(parse_input(&["zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string()])), Ok(Some(26)));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(parse_input(&[]), "");
+        assert_eq!(solution(parse_input(&[])), Ok(None));
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry {
+    File(usize),
+    Dir(Dir),
+}
+
+/// A directory as the transcript shows it. `listed` tells whether its
+/// contents were ever listed; a directory that was only `cd`'d into may have
+/// more in it than we know.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dir {
+    pub entries: BTreeMap<String, Entry>,
+    pub listed: bool,
+}
+
+impl Dir {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, path: &[String]) -> Option<&Dir> {
+        match path {
+            [] => Some(self),
+            [name, rest @ ..] => match self.entries.get(name)? {
+                Entry::Dir(dir) => dir.get(rest),
+                Entry::File(_) => None,
+            },
+        }
+    }
+
+    pub fn get_mut(&mut self, path: &[String]) -> Option<&mut Dir> {
+        match path {
+            [] => Some(self),
+            [name, rest @ ..] => match self.entries.get_mut(name)? {
+                Entry::Dir(dir) => dir.get_mut(rest),
+                Entry::File(_) => None,
+            },
+        }
+    }
+
+    /// The size of every file below, however deep.
+    pub fn size(&self) -> usize {
+        self.entries
+            .values()
+            .map(|entry| match entry {
+                Entry::File(size) => *size,
+                Entry::Dir(dir) => dir.size(),
+            })
+            .sum()
+    }
+}
+
+/// `/` for the root, `/a/e` for `a`'s `e`.
+pub fn path(components: &[String]) -> String {
+    format!("/{}", components.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dir(entries: &[(&str, Entry)]) -> Entry {
+        Entry::Dir(Dir {
+            entries: entries
+                .iter()
+                .map(|(name, entry)| (name.to_string(), entry.clone()))
+                .collect(),
+            listed: true,
+        })
+    }
+
+    #[test]
+    fn test_sizes() {
+        let Entry::Dir(root) = dir(&[
+            ("a", dir(&[("e", dir(&[("i", Entry::File(584))]))])),
+            ("b.txt", Entry::File(100)),
+            ("d", dir(&[])),
+        ]) else {
+            unreachable!()
+        };
+        assert_eq!(root.size(), 684);
+        let a = ["a".to_string()];
+        assert_eq!(root.get(&a).unwrap().size(), 584);
+        assert_eq!(root.get(&["b.txt".to_string()]), None);
+        assert_eq!(path(&[]), "/");
+        assert_eq!(path(&[a[0].clone(), "e".to_string()]), "/a/e");
+    }
+}
@@ -0,0 +1,275 @@
+use std::{fs::read_dir, io, path::Path};
+
+use common::rng::Rng;
+
+use crate::fs::{path, Dir, Entry};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Traversal {
+    /// Depth first, in name order, with `cd ..` to come back up.
+    Dfs,
+    /// Directories in any order once their parent has been listed, reached
+    /// by absolute paths, relative paths or one `cd` at a time.
+    Random,
+}
+
+impl Traversal {
+    pub fn by_name(name: &str) -> Option<Traversal> {
+        match name {
+            "dfs" => Some(Traversal::Dfs),
+            "random" => Some(Traversal::Random),
+            _ => None,
+        }
+    }
+}
+
+// a name the transcript can show: one word, and not one `cd` treats
+// specially
+fn representable(name: &str) -> bool {
+    !name.is_empty() && !name.contains(char::is_whitespace) && name != "." && name != ".."
+}
+
+/// The files and directories below `path` on disk. Anything that isn't a
+/// plain file or directory (symlinks, say) and names the transcript can't
+/// show are left out.
+pub fn from_disk(path: &Path) -> io::Result<Dir> {
+    let mut dir = Dir {
+        listed: true,
+        ..Dir::new()
+    };
+    for entry in read_dir(path)? {
+        let entry = entry?;
+        let Some(name) = entry.file_name().to_str().map(|name| name.to_string()) else {
+            continue;
+        };
+        if !representable(&name) {
+            continue;
+        }
+        let kind = entry.file_type()?;
+        if kind.is_dir() {
+            dir.entries
+                .insert(name, Entry::Dir(from_disk(&entry.path())?));
+        } else if kind.is_file() {
+            let size = entry.metadata()?.len() as usize;
+            dir.entries.insert(name, Entry::File(size));
+        }
+    }
+    Ok(dir)
+}
+
+// like `qtz` or `hvlrb.jpn`
+fn random_name(rng: &mut Rng) -> String {
+    let mut letters = |n: i64| -> String {
+        let len = rng.between(1, n);
+        (0..len)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect()
+    };
+    let stem = letters(8);
+    let extension = letters(3);
+    if rng.chance(0.5) {
+        format!("{}.{}", stem, extension)
+    } else {
+        stem
+    }
+}
+
+fn add(rng: &mut Rng, dir: &mut Dir, entry: Entry) -> String {
+    loop {
+        let name = random_name(rng);
+        if !dir.entries.contains_key(&name) {
+            dir.entries.insert(name.clone(), entry);
+            return name;
+        }
+    }
+}
+
+/// A filesystem of `dirs` directories besides the root and `files` files of
+/// 1 to `max_size`, each put in a directory picked at random.
+pub fn synthetic(rng: &mut Rng, dirs: usize, files: usize, max_size: usize) -> Dir {
+    let listed = || Dir {
+        listed: true,
+        ..Dir::new()
+    };
+    let mut root = listed();
+    let mut paths: Vec<Vec<String>> = vec![vec![]];
+    for _ in 0..dirs {
+        let mut path = paths[rng.index(paths.len())].clone();
+        let parent = root.get_mut(&path).unwrap();
+        path.push(add(rng, parent, Entry::Dir(listed())));
+        paths.push(path);
+    }
+    for _ in 0..files {
+        let size = rng.between(1, max_size as i64) as usize;
+        let parent = root.get_mut(&paths[rng.index(paths.len())]).unwrap();
+        add(rng, parent, Entry::File(size));
+    }
+    root
+}
+
+fn list(dir: &Dir, rng: Option<&mut Rng>, lines: &mut Vec<String>) {
+    let mut entries: Vec<String> = dir
+        .entries
+        .iter()
+        .map(|(name, entry)| match entry {
+            Entry::Dir(_) => format!("dir {}", name),
+            Entry::File(size) => format!("{} {}", size, name),
+        })
+        .collect();
+    if let Some(rng) = rng {
+        rng.shuffle(&mut entries);
+    }
+    lines.push("$ ls".to_string());
+    lines.extend(entries);
+}
+
+fn dfs(dir: &Dir, lines: &mut Vec<String>) {
+    list(dir, None, lines);
+    for (name, entry) in &dir.entries {
+        if let Entry::Dir(sub) = entry {
+            lines.push(format!("$ cd {}", name));
+            dfs(sub, lines);
+            lines.push("$ cd ..".to_string());
+        }
+    }
+}
+
+// the commands that get from `cwd` to `target`
+fn cd(rng: &mut Rng, cwd: &[String], target: &[String]) -> Vec<String> {
+    let common = cwd.iter().zip(target).take_while(|(a, b)| a == b).count();
+    let steps = steps_between(cwd.len() - common, &target[common..]);
+    match rng.below(3) {
+        0 => vec![format!("$ cd {}", path(target))],
+        1 => vec![format!("$ cd {}", steps.join("/"))],
+        _ => steps.iter().map(|step| format!("$ cd {}", step)).collect(),
+    }
+}
+
+fn steps_between(up: usize, down: &[String]) -> Vec<String> {
+    let mut steps = vec!["..".to_string(); up];
+    steps.extend(down.iter().cloned());
+    steps
+}
+
+/// A transcript that lists every directory of `root` once, starting with
+/// `$ cd /`.
+pub fn transcript(rng: &mut Rng, root: &Dir, traversal: Traversal) -> Vec<String> {
+    let mut lines = vec!["$ cd /".to_string()];
+    match traversal {
+        Traversal::Dfs => dfs(root, &mut lines),
+        Traversal::Random => {
+            let mut cwd = vec![];
+            let mut pending: Vec<Vec<String>> = vec![vec![]];
+            while !pending.is_empty() {
+                let target = pending.swap_remove(rng.index(pending.len()));
+                if target != cwd {
+                    lines.extend(cd(rng, &cwd, &target));
+                    cwd = target;
+                }
+                let dir = root.get(&cwd).unwrap();
+                list(dir, Some(rng), &mut lines);
+                for (name, entry) in &dir.entries {
+                    if let Entry::Dir(_) = entry {
+                        let mut sub = cwd.clone();
+                        sub.push(name.clone());
+                        pending.push(sub);
+                    }
+                }
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+    use crate::{shell::parse_strict, tree::Tree};
+
+    fn sizes(root: &Dir) -> Vec<(String, usize)> {
+        let tree = Tree::new(root);
+        tree.walk()
+            .map(|(id, _)| (tree.path(id), tree[id].size))
+            .collect()
+    }
+
+    #[test]
+    fn test_dfs() {
+        let lines: Vec<String> = include_str!("../example.txt")
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+        let root = parse_strict(&lines).unwrap();
+        let transcript = transcript(&mut Rng::new(0), &root, Traversal::Dfs);
+        assert_eq!(
+            transcript[..8],
+            [
+                "$ cd /",
+                "$ ls",
+                "dir a",
+                "14848514 b.txt",
+                "8504156 c.dat",
+                "dir d",
+                "$ cd a",
+                "$ ls"
+            ]
+        );
+        // the same commands, ending with a last `cd ..`
+        assert_eq!(transcript.len(), lines.len() + 1);
+        assert_eq!(parse_strict(&transcript).unwrap(), root);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let root = synthetic(&mut rng, 30, 100, 300_000);
+            for traversal in [Traversal::Dfs, Traversal::Random] {
+                let lines = transcript(&mut rng, &root, traversal);
+                let parsed = parse_strict(&lines).unwrap();
+                assert_eq!(sizes(&parsed), sizes(&root));
+                assert_eq!(parsed, root);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_paths() {
+        // every way of getting around shows up
+        let mut rng = Rng::new(2022);
+        let root = synthetic(&mut rng, 50, 0, 1);
+        let lines = transcript(&mut rng, &root, Traversal::Random);
+        let cds: Vec<&str> = lines
+            .iter()
+            .filter_map(|line| line.strip_prefix("$ cd "))
+            .collect();
+        assert!(cds[1..].iter().any(|arg| arg.starts_with('/')));
+        assert!(cds.iter().any(|arg| arg.starts_with("../")));
+        assert!(cds.contains(&".."));
+        assert_eq!(lines.iter().filter(|line| *line == "$ ls").count(), 51);
+    }
+
+    #[test]
+    fn test_from_disk() {
+        let base = env::temp_dir().join(format!("day07-generate-{}", std::process::id()));
+        fs::create_dir_all(base.join("a/e")).unwrap();
+        fs::create_dir_all(base.join("d")).unwrap();
+        fs::write(base.join("b.txt"), vec![0; 1400]).unwrap();
+        fs::write(base.join("a/f"), vec![0; 291]).unwrap();
+        fs::write(base.join("a/e/i"), vec![0; 584]).unwrap();
+        fs::write(base.join("d/k"), vec![0; 72]).unwrap();
+        fs::write(base.join("d/has space"), vec![0; 10]).unwrap();
+        let root = from_disk(&base);
+        fs::remove_dir_all(&base).unwrap();
+
+        let root = root.unwrap();
+        let tree = Tree::new(&root);
+        assert_eq!(tree[tree.root()].size, 1400 + 291 + 584 + 72);
+        assert_eq!(tree[tree.lookup("/a").unwrap()].size, 875);
+        assert_eq!(tree.lookup("/d/has space"), None);
+        let lines = transcript(&mut Rng::new(7), &root, Traversal::Random);
+        assert_eq!(parse_strict(&lines).unwrap(), root);
+    }
+}
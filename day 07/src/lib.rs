@@ -0,0 +1,7 @@
+pub mod fs;
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod render;
+pub mod shell;
+pub mod tree;
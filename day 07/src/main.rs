@@ -0,0 +1,131 @@
+use std::{
+    env,
+    io::{self, BufRead},
+    path::Path,
+    process,
+};
+
+use common::rng::Rng;
+use day07::{
+    fs::Dir,
+    generate::{from_disk, synthetic, transcript, Traversal},
+    part1, part2,
+    render::{render_du, render_json, render_tree, Order},
+    shell::{parse_lenient, parse_strict},
+    tree::Tree,
+};
+
+fn usage() -> ! {
+    eprintln!("usage: day07 [--strict] < input.txt");
+    eprintln!("       day07 [--strict] --tree [name|size] < input.txt");
+    eprintln!("       day07 [--strict] --du <depth> [path] [name|size] < input.txt");
+    eprintln!("       day07 [--strict] --json < input.txt");
+    eprintln!("       day07 --generate <directory> [dfs|random] [seed] > input.txt");
+    eprintln!("       day07 --synthetic <directories> <files> [dfs|random] [seed] > input.txt");
+    process::exit(2);
+}
+
+const SEED: u64 = 2022;
+
+fn traversal(args: &[&str]) -> (Traversal, u64) {
+    let by_name = |name| Traversal::by_name(name).unwrap_or_else(|| usage());
+    match *args {
+        [] => (Traversal::Dfs, SEED),
+        [name] => (by_name(name), SEED),
+        [name, seed] => (by_name(name), seed.parse().unwrap_or_else(|_| usage())),
+        _ => usage(),
+    }
+}
+
+fn print_transcript(rng: &mut Rng, root: &Dir, traversal: Traversal) {
+    for line in transcript(rng, root, traversal) {
+        println!("{}", line);
+    }
+}
+
+enum Mode {
+    Solve,
+    Tree(Order),
+    Du(usize, String, Order),
+    Json,
+}
+
+fn read(strict: bool) -> Dir {
+    let lines = io::stdin().lock().lines();
+    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
+    if strict {
+        parse_strict(&lines).unwrap_or_else(|warning| {
+            eprintln!("{}", warning);
+            process::exit(1);
+        })
+    } else {
+        // warn about whatever looks odd, but carry on
+        let (root, warnings) = parse_lenient(&lines);
+        for warning in warnings {
+            eprintln!("{}", warning);
+        }
+        root
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    let (strict, args) = match args[..] {
+        ["--strict", ref rest @ ..] => (true, rest),
+        ref rest => (false, rest),
+    };
+    let number = |arg: &str| arg.parse().unwrap_or_else(|_| usage());
+    match *args {
+        ["--generate", dir, ref rest @ ..] => {
+            let (traversal, seed) = traversal(rest);
+            let root = from_disk(Path::new(dir)).unwrap_or_else(|error| {
+                eprintln!("{}: {}", dir, error);
+                process::exit(1);
+            });
+            return print_transcript(&mut Rng::new(seed), &root, traversal);
+        }
+        ["--synthetic", dirs, files, ref rest @ ..] => {
+            let (traversal, seed) = traversal(rest);
+            let (dirs, files): (usize, usize) = (number(dirs), number(files));
+            // about half the disk full, like the puzzle input
+            let max_size = part2::DISK / files.max(1);
+            let mut rng = Rng::new(seed);
+            let root = synthetic(&mut rng, dirs, files, max_size);
+            return print_transcript(&mut rng, &root, traversal);
+        }
+        _ => {}
+    }
+    let order = |name: &str| Order::by_name(name).unwrap_or_else(|| usage());
+    let mode = match *args {
+        [] => Mode::Solve,
+        ["--tree"] => Mode::Tree(Order::Name),
+        ["--tree", name] => Mode::Tree(order(name)),
+        ["--du", n] => Mode::Du(number(n), "/".to_string(), Order::Name),
+        ["--du", n, arg] if arg.starts_with('/') => {
+            Mode::Du(number(n), arg.to_string(), Order::Name)
+        }
+        ["--du", n, name] => Mode::Du(number(n), "/".to_string(), order(name)),
+        ["--du", n, path, name] => Mode::Du(number(n), path.to_string(), order(name)),
+        ["--json"] => Mode::Json,
+        _ => usage(),
+    };
+    let tree = Tree::new(&read(strict));
+    let lines = match mode {
+        Mode::Solve => vec![
+            part1::solution(&tree).to_string(),
+            part2::solution(&tree).to_string(),
+        ],
+        Mode::Tree(order) => render_tree(&tree, order),
+        Mode::Du(depth, path, order) => {
+            render_du(&tree, &path, depth, order).unwrap_or_else(|| {
+                eprintln!("no such directory: {}", path);
+                process::exit(1);
+            })
+        }
+        Mode::Json => render_json(&tree),
+    };
+    for line in lines {
+        println!("{}", line);
+    }
+}
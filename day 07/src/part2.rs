@@ -0,0 +1,58 @@
+use crate::{shell::parse_lenient, tree::Tree};
+
+pub fn parse_input(lines: &[String]) -> Tree {
+    Tree::new(&parse_lenient(lines).0)
+}
+
+pub const DISK: usize = 70_000_000;
+pub const NEEDED: usize = 30_000_000;
+
+pub fn solution(tree: &Tree) -> usize {
+    let free = DISK - tree[tree.root()].size;
+    tree.find(|node| node.is_dir() && free + node.size >= NEEDED)
+        .map(|node| node.size)
+        .min()
+        .unwrap()
+}
+
+pub fn solve(lines: &[String]) -> usize {
+    solution(&parse_input(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let lines = [
+            "$ cd /",
+            "$ ls",
+            "dir a",
+            "14848514 b.txt",
+            "8504156 c.dat",
+            "dir d",
+            "$ cd a",
+            "$ ls",
+            "dir e",
+            "29116 f",
+            "2557 g",
+            "62596 h.lst",
+            "$ cd e",
+            "$ ls",
+            "584 i",
+            "$ cd ..",
+            "$ cd ..",
+            "$ cd d",
+            "$ ls",
+            "4060174 j",
+            "8033020 d.log",
+            "5626152 d.ext",
+            "7214296 k",
+        ];
+
+        let lines: Vec<String> = lines.iter().map(|x| x.to_string()).collect();
+        let node = parse_input(&lines);
+        assert_eq!(solution(&node), 24933642);
+    }
+}
@@ -0,0 +1,251 @@
+use std::cmp::Reverse;
+
+use crate::tree::{Kind, NodeId, Tree};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Name,
+    // biggest first, then by name
+    Size,
+}
+
+impl Order {
+    pub fn by_name(name: &str) -> Option<Order> {
+        match name {
+            "name" => Some(Order::Name),
+            "size" => Some(Order::Size),
+            _ => None,
+        }
+    }
+}
+
+fn children(tree: &Tree, id: NodeId, order: Order) -> Vec<NodeId> {
+    let mut children = tree[id].children.clone();
+    if order == Order::Size {
+        // stable, so equal sizes stay sorted by name
+        children.sort_by_key(|&child| Reverse(tree[child].size));
+    }
+    children
+}
+
+/// Like `tree`, with sizes, and a `/` after directory names.
+pub fn render_tree(tree: &Tree, order: Order) -> Vec<String> {
+    let root = tree.root();
+    let mut lines = vec![format!("/ ({})", tree[root].size)];
+    tree_lines(tree, root, order, "", &mut lines);
+    lines
+}
+
+fn tree_lines(tree: &Tree, id: NodeId, order: Order, prefix: &str, lines: &mut Vec<String>) {
+    let children = children(tree, id, order);
+    for (i, &child) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        let node = &tree[child];
+        let slash = if node.is_dir() { "/" } else { "" };
+        let branch = if last { "└── " } else { "├── " };
+        lines.push(format!(
+            "{}{}{}{} ({})",
+            prefix, branch, node.name, slash, node.size
+        ));
+        let indent = if last { "    " } else { "│   " };
+        tree_lines(tree, child, order, &format!("{}{}", prefix, indent), lines);
+    }
+}
+
+/// A size the way `du -h` shows it: powers of 1024, rounded up, with one
+/// decimal below 10.
+pub fn human(size: usize) -> String {
+    const UNITS: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+    if size < 1024 {
+        return size.to_string();
+    }
+    let mut value = size as f64 / 1024.0;
+    let mut unit = 0;
+    loop {
+        let tenths = (value * 10.0).ceil() / 10.0;
+        let rounded = if tenths < 10.0 { tenths } else { value.ceil() };
+        if rounded < 1024.0 || unit + 1 == UNITS.len() {
+            return if rounded < 10.0 {
+                format!("{:.1}{}", rounded, UNITS[unit])
+            } else {
+                format!("{}{}", rounded, UNITS[unit])
+            };
+        }
+        value /= 1024.0;
+        unit += 1;
+    }
+}
+
+/// Like `du -h --max-depth=depth path`: every directory at most `depth` below
+/// `path`, after the ones inside it. `None` if `path` isn't a directory.
+pub fn render_du(tree: &Tree, path: &str, depth: usize, order: Order) -> Option<Vec<String>> {
+    let id = tree.lookup(path).filter(|&id| tree[id].is_dir())?;
+    let mut lines = Vec::new();
+    du_lines(tree, id, depth, order, &mut lines);
+    Some(lines)
+}
+
+fn du_lines(tree: &Tree, id: NodeId, depth: usize, order: Order, lines: &mut Vec<String>) {
+    if depth > 0 {
+        for child in children(tree, id, order) {
+            if tree[child].is_dir() {
+                du_lines(tree, child, depth - 1, order, lines);
+            }
+        }
+    }
+    lines.push(format!("{}\t{}", human(tree[id].size), tree.path(id)));
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// The whole hierarchy, one node per line, directories with their children
+/// indented below them.
+pub fn render_json(tree: &Tree) -> Vec<String> {
+    let mut lines = Vec::new();
+    json_lines(tree, tree.root(), 0, true, &mut lines);
+    lines
+}
+
+fn json_lines(tree: &Tree, id: NodeId, depth: usize, last: bool, lines: &mut Vec<String>) {
+    let node = &tree[id];
+    let indent = "  ".repeat(depth);
+    let comma = if last { "" } else { "," };
+    let fields = format!(
+        "{{\"name\": {}, \"type\": \"{}\", \"size\": {}",
+        json_string(&node.name),
+        if node.kind == Kind::Dir {
+            "dir"
+        } else {
+            "file"
+        },
+        node.size
+    );
+    match node.kind {
+        Kind::File => lines.push(format!("{}{}}}{}", indent, fields, comma)),
+        Kind::Dir if node.children.is_empty() => {
+            lines.push(format!("{}{}, \"children\": []}}{}", indent, fields, comma))
+        }
+        Kind::Dir => {
+            lines.push(format!("{}{}, \"children\": [", indent, fields));
+            for (i, &child) in node.children.iter().enumerate() {
+                let last = i + 1 == node.children.len();
+                json_lines(tree, child, depth + 1, last, lines);
+            }
+            lines.push(format!("{}]}}{}", indent, comma));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fs::Dir, shell::parse_strict};
+
+    fn example() -> Tree {
+        let lines: Vec<String> = include_str!("../example.txt")
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+        Tree::new(&parse_strict(&lines).unwrap())
+    }
+
+    #[test]
+    fn test_tree() {
+        let tree = example();
+        assert_eq!(
+            render_tree(&tree, Order::Name),
+            [
+                "/ (48381165)",
+                "├── a/ (94853)",
+                "│   ├── e/ (584)",
+                "│   │   └── i (584)",
+                "│   ├── f (29116)",
+                "│   ├── g (2557)",
+                "│   └── h.lst (62596)",
+                "├── b.txt (14848514)",
+                "├── c.dat (8504156)",
+                "└── d/ (24933642)",
+                "    ├── d.ext (5626152)",
+                "    ├── d.log (8033020)",
+                "    ├── j (4060174)",
+                "    └── k (7214296)",
+            ]
+        );
+        assert_eq!(
+            render_tree(&tree, Order::Size)[1..4],
+            [
+                "├── d/ (24933642)",
+                "│   ├── d.log (8033020)",
+                "│   ├── k (7214296)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_human() {
+        assert_eq!(human(0), "0");
+        assert_eq!(human(1023), "1023");
+        assert_eq!(human(1024), "1.0K");
+        assert_eq!(human(1025), "1.1K");
+        assert_eq!(human(10 * 1024), "10K");
+        assert_eq!(human(94853), "93K");
+        assert_eq!(human(8504156), "8.2M");
+        assert_eq!(human(48381165), "47M");
+        assert_eq!(human(1024 * 1024 - 1), "1.0M");
+    }
+
+    #[test]
+    fn test_du() {
+        let tree = example();
+        assert_eq!(
+            render_du(&tree, "/", 1, Order::Name).unwrap(),
+            ["93K\t/a", "24M\t/d", "47M\t/"]
+        );
+        assert_eq!(
+            render_du(&tree, "/", 2, Order::Size).unwrap(),
+            ["24M\t/d", "584\t/a/e", "93K\t/a", "47M\t/"]
+        );
+        assert_eq!(render_du(&tree, "/a", 0, Order::Name).unwrap(), ["93K\t/a"]);
+        assert_eq!(render_du(&tree, "/a/f", 1, Order::Name), None);
+    }
+
+    #[test]
+    fn test_json() {
+        let tree = example();
+        let json = render_json(&tree);
+        assert_eq!(
+            json[..4],
+            [
+                "{\"name\": \"/\", \"type\": \"dir\", \"size\": 48381165, \"children\": [",
+                "  {\"name\": \"a\", \"type\": \"dir\", \"size\": 94853, \"children\": [",
+                "    {\"name\": \"e\", \"type\": \"dir\", \"size\": 584, \"children\": [",
+                "      {\"name\": \"i\", \"type\": \"file\", \"size\": 584}",
+            ]
+        );
+        assert_eq!(json[4], "    ]},");
+        assert_eq!(json.last().unwrap(), "]}");
+        assert_eq!(json.len(), 14 + 4);
+
+        let mut root = Dir::new();
+        root.entries.insert(
+            "say \"hi\"\\".to_string(),
+            crate::fs::Entry::Dir(Dir::new()),
+        );
+        assert_eq!(
+            render_json(&Tree::new(&root))[1],
+            "  {\"name\": \"say \\\"hi\\\"\\\\\", \"type\": \"dir\", \"size\": 0, \"children\": []}"
+        );
+    }
+}
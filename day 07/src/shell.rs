@@ -0,0 +1,527 @@
+use std::{collections::BTreeMap, fmt};
+
+use common::parse::{int, parse_line};
+use nom::{
+    branch::alt,
+    bytes::complete::{is_not, tag},
+    character::complete::{char, space0, space1},
+    combinator::{eof, map, value, verify},
+    sequence::{delimited, pair, preceded, separated_pair, tuple},
+    IResult,
+};
+
+use crate::fs::{path, Dir, Entry};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WarningKind {
+    /// `cd` into a directory its parent's listing doesn't have.
+    NoSuchDirectory(String),
+    NotADirectory(String),
+    /// `cd ..` at the root.
+    AboveRoot,
+    /// The same directory listed again, with the same contents.
+    RepeatedListing(String),
+    /// A listing that disagrees about `name` with an earlier one.
+    ConflictingListing {
+        path: String,
+        name: String,
+    },
+    OutputWithoutListing(String),
+    UnknownCommand(String),
+    Malformed(String),
+}
+
+/// Something odd in the transcript, at a 1-based line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub line: usize,
+    pub kind: WarningKind,
+}
+
+impl WarningKind {
+    /// Whether the transcript contradicts itself (or can't be read), rather
+    /// than just doing something twice.
+    pub fn is_inconsistent(&self) -> bool {
+        !matches!(self, WarningKind::RepeatedListing(_))
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            WarningKind::NoSuchDirectory(path) => write!(f, "no such directory: {}", path),
+            WarningKind::NotADirectory(path) => write!(f, "not a directory: {}", path),
+            WarningKind::AboveRoot => write!(f, "already at the root"),
+            WarningKind::RepeatedListing(path) => write!(f, "{} listed again", path),
+            WarningKind::ConflictingListing { path, name } => {
+                write!(f, "{} listed differently from before: {}", path, name)
+            }
+            WarningKind::OutputWithoutListing(text) => {
+                write!(f, "output without ls: {}", text)
+            }
+            WarningKind::UnknownCommand(text) => write!(f, "unknown command: {}", text),
+            WarningKind::Malformed(text) => write!(f, "can't read: {}", text),
+        }
+    }
+}
+
+// the output of an `ls` so far
+struct Listing {
+    line: usize,
+    path: Vec<String>,
+    entries: BTreeMap<String, Entry>,
+}
+
+/// Replays a transcript of `cd` and `ls` against a filesystem that starts out
+/// empty. Paths can be absolute or relative, with `.` and `..` anywhere.
+#[derive(Default)]
+pub struct Shell {
+    root: Dir,
+    cwd: Vec<String>,
+    listing: Option<Listing>,
+    warnings: Vec<Warning>,
+}
+
+impl Shell {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cwd(&self) -> String {
+        path(&self.cwd)
+    }
+
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    fn warn(&mut self, line: usize, kind: WarningKind) {
+        self.warnings.push(Warning { line, kind });
+    }
+
+    /// Takes the next line of the transcript, `index` being its 0-based
+    /// position.
+    pub fn line(&mut self, index: usize, text: &str) {
+        let line = index + 1;
+        if text.trim().is_empty() {
+            return;
+        }
+        let command = prompt(text).is_ok();
+        if command {
+            self.flush();
+        }
+        match parse_line(delimited(space0, transcript_line, space0), index, text) {
+            Ok(Line::Cd(arg)) => self.cd(line, arg),
+            Ok(Line::Ls) => {
+                self.listing = Some(Listing {
+                    line,
+                    path: self.cwd.clone(),
+                    entries: BTreeMap::new(),
+                })
+            }
+            Ok(Line::Dir(name)) => self.output(line, text, name, Entry::Dir(Dir::new())),
+            Ok(Line::File(size, name)) => self.output(line, text, name, Entry::File(size)),
+            Err(_) if command => self.warn(line, WarningKind::UnknownCommand(text.to_string())),
+            Err(_) => self.warn(line, WarningKind::Malformed(text.to_string())),
+        }
+    }
+
+    fn cd(&mut self, line: usize, arg: &str) {
+        let mut target = if arg.starts_with('/') {
+            vec![]
+        } else {
+            self.cwd.clone()
+        };
+        for name in arg.split('/') {
+            match name {
+                "" | "." => {}
+                ".." => {
+                    if target.pop().is_none() {
+                        self.warn(line, WarningKind::AboveRoot);
+                    }
+                }
+                _ => {
+                    let parent = self.root.get_mut(&target).unwrap();
+                    target.push(name.to_string());
+                    match parent.entries.get(name) {
+                        Some(Entry::Dir(_)) => {}
+                        Some(Entry::File(_)) => {
+                            self.warn(line, WarningKind::NotADirectory(path(&target)));
+                            return;
+                        }
+                        None => {
+                            // nobody said what's in an unlisted parent, so it
+                            // may well be there
+                            let listed = parent.listed;
+                            parent
+                                .entries
+                                .insert(name.to_string(), Entry::Dir(Dir::new()));
+                            if listed {
+                                self.warn(line, WarningKind::NoSuchDirectory(path(&target)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        self.cwd = target;
+    }
+
+    fn output(&mut self, line: usize, text: &str, name: &str, entry: Entry) {
+        let Some(listing) = &mut self.listing else {
+            self.warn(line, WarningKind::OutputWithoutListing(text.to_string()));
+            return;
+        };
+        let path = path(&listing.path);
+        match listing.entries.get(name) {
+            Some(seen) if !same(seen, &entry) => {
+                let name = name.to_string();
+                self.warn(line, WarningKind::ConflictingListing { path, name });
+            }
+            Some(_) => {}
+            None => {
+                listing.entries.insert(name.to_string(), entry);
+            }
+        }
+    }
+
+    // merges the listing in progress into the directory it's of
+    fn flush(&mut self) {
+        let Some(listing) = self.listing.take() else {
+            return;
+        };
+        let dir = self.root.get_mut(&listing.path).unwrap();
+        let path = path(&listing.path);
+        let mut warnings = Vec::new();
+        let listed = dir.listed;
+        let conflicts: Vec<&String> = if listed {
+            let names = dir.entries.keys().chain(listing.entries.keys());
+            let mut conflicts: Vec<&String> = names
+                .filter(
+                    |&name| match (dir.entries.get(name), listing.entries.get(name)) {
+                        (Some(before), Some(now)) => !same(before, now),
+                        _ => true,
+                    },
+                )
+                .collect();
+            conflicts.sort();
+            conflicts.dedup();
+            if conflicts.is_empty() {
+                warnings.push(WarningKind::RepeatedListing(path.clone()));
+            }
+            conflicts
+        } else {
+            // what `cd` went into before anything was listed has to be there
+            dir.entries
+                .iter()
+                .filter(|&(name, before)| {
+                    !listing
+                        .entries
+                        .get(name)
+                        .is_some_and(|now| same(before, now))
+                })
+                .map(|(name, _)| name)
+                .collect()
+        };
+        for name in conflicts {
+            let name = name.clone();
+            let path = path.clone();
+            warnings.push(WarningKind::ConflictingListing { path, name });
+        }
+        // the latest listing wins, but directories keep what's known of them,
+        // and so do the ones only `cd` knows about
+        let mut before = std::mem::take(&mut dir.entries);
+        dir.entries = listing
+            .entries
+            .into_iter()
+            .map(|(name, entry)| match (before.remove(&name), entry) {
+                (Some(Entry::Dir(old)), Entry::Dir(_)) => (name, Entry::Dir(old)),
+                (Some(old), _) if !listed => (name, old),
+                (_, entry) => (name, entry),
+            })
+            .collect();
+        if !listed {
+            dir.entries.extend(before);
+        }
+        dir.listed = true;
+        for kind in warnings {
+            self.warn(listing.line, kind);
+        }
+    }
+
+    pub fn finish(mut self) -> (Dir, Vec<Warning>) {
+        self.flush();
+        (self.root, self.warnings)
+    }
+}
+
+fn valid(name: &str) -> bool {
+    !name.contains('/') && name != "." && name != ".."
+}
+
+// a line of the transcript, command or not
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(usize, &'a str),
+}
+
+fn word(input: &str) -> IResult<&str, &str> {
+    is_not(" \t")(input)
+}
+
+// the `$` that starts a command
+fn prompt(input: &str) -> IResult<&str, ()> {
+    value((), tuple((space0, char('$'), alt((space1, eof)))))(input)
+}
+
+fn transcript_line(input: &str) -> IResult<&str, Line<'_>> {
+    let name = |input| verify(word, |name: &str| valid(name))(input);
+    alt((
+        map(preceded(tuple((prompt, tag("cd"), space1)), word), Line::Cd),
+        value(Line::Ls, pair(prompt, tag("ls"))),
+        map(preceded(pair(tag("dir"), space1), name), Line::Dir),
+        map(separated_pair(int, space1, name), |(size, name)| {
+            Line::File(size, name)
+        }),
+    ))(input)
+}
+
+// whether two listings agree about an entry, whatever is inside directories
+fn same(a: &Entry, b: &Entry) -> bool {
+    match (a, b) {
+        (Entry::File(a), Entry::File(b)) => a == b,
+        (Entry::Dir(_), Entry::Dir(_)) => true,
+        _ => false,
+    }
+}
+
+/// The filesystem and everything odd about the transcript.
+pub fn parse_lenient(lines: &[String]) -> (Dir, Vec<Warning>) {
+    let mut shell = Shell::new();
+    for (index, line) in lines.iter().enumerate() {
+        shell.line(index, line);
+    }
+    shell.finish()
+}
+
+/// The filesystem, unless the transcript contradicts itself. Listing a
+/// directory again is fine as long as nothing changed.
+pub fn parse_strict(lines: &[String]) -> Result<Dir, Warning> {
+    let (root, warnings) = parse_lenient(lines);
+    match warnings.into_iter().find(|w| w.kind.is_inconsistent()) {
+        Some(warning) => Err(warning),
+        None => Ok(root),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(|line| line.trim().to_string()).collect()
+    }
+
+    fn kinds(warnings: &[Warning]) -> Vec<(usize, WarningKind)> {
+        warnings.iter().map(|w| (w.line, w.kind.clone())).collect()
+    }
+
+    const EXAMPLE: &str = "$ cd /
+        $ ls
+        dir a
+        14848514 b.txt
+        8504156 c.dat
+        dir d
+        $ cd a
+        $ ls
+        dir e
+        29116 f
+        2557 g
+        62596 h.lst
+        $ cd e
+        $ ls
+        584 i
+        $ cd ..
+        $ cd ..
+        $ cd d
+        $ ls
+        4060174 j
+        8033020 d.log
+        5626152 d.ext
+        7214296 k";
+
+    #[test]
+    fn test_example() {
+        let root = parse_strict(&lines(EXAMPLE)).unwrap();
+        assert_eq!(root.size(), 48381165);
+        let e = ["a".to_string(), "e".to_string()];
+        assert_eq!(root.get(&e).unwrap().size(), 584);
+        assert!(root.get(&e).unwrap().listed);
+    }
+
+    #[test]
+    fn test_paths() {
+        // the example again, jumping around with absolute and relative paths
+        let transcript = "$ ls
+            dir a
+            14848514 b.txt
+            8504156 c.dat
+            dir d
+            $ cd /a/e
+            $ ls
+            584 i
+            $ cd ../.
+            $ ls
+            dir e
+            29116 f
+            2557 g
+            62596 h.lst
+            $ cd /
+            $ cd d
+            $ ls
+            4060174 j
+            8033020 d.log
+            5626152 d.ext
+            7214296 k
+            $ cd ../a/e/../../d/";
+        let mut shell = Shell::new();
+        for (index, line) in lines(transcript).iter().enumerate() {
+            shell.line(index, line);
+        }
+        assert_eq!(shell.cwd(), "/d");
+        let (root, warnings) = shell.finish();
+        assert_eq!(warnings, []);
+        assert_eq!(root, parse_strict(&lines(EXAMPLE)).unwrap());
+    }
+
+    #[test]
+    fn test_repeated() {
+        let transcript = "$ ls
+            dir a
+            10 b
+            $ cd a
+            $ ls
+            20 c
+            $ cd /
+            $ ls
+            dir a
+            10 b";
+        let (root, warnings) = parse_lenient(&lines(transcript));
+        assert_eq!(
+            kinds(&warnings),
+            [(8, WarningKind::RepeatedListing("/".to_string()))]
+        );
+        // listing again doesn't forget what's in a
+        assert_eq!(root.size(), 30);
+        assert_eq!(parse_strict(&lines(transcript)).unwrap(), root);
+    }
+
+    #[test]
+    fn test_conflicting() {
+        let transcript = "$ ls
+            dir a
+            10 b
+            10 b
+            11 b
+            $ cd a
+            $ ls
+            20 c
+            $ cd ..
+            $ ls
+            12 b
+            dir e";
+        let (root, warnings) = parse_lenient(&lines(transcript));
+        let conflict = |line, name: &str| {
+            let path = "/".to_string();
+            let name = name.to_string();
+            (line, WarningKind::ConflictingListing { path, name })
+        };
+        assert_eq!(
+            kinds(&warnings),
+            [
+                conflict(5, "b"),
+                conflict(10, "a"),
+                conflict(10, "b"),
+                conflict(10, "e")
+            ]
+        );
+        // the latest listing wins
+        assert_eq!(root.entries.keys().collect::<Vec<_>>(), ["b", "e"]);
+        assert_eq!(root.size(), 12);
+        assert_eq!(parse_strict(&lines(transcript)).unwrap_err().line, 5);
+    }
+
+    #[test]
+    fn test_unlisted() {
+        // nothing says what's in /, so a can be in it
+        let (root, warnings) = parse_lenient(&lines("$ cd a\n$ ls\n5 x"));
+        assert_eq!(warnings, []);
+        assert_eq!(root.size(), 5);
+        assert!(!root.listed);
+
+        let transcript = "$ ls
+            5 x
+            $ cd a
+            $ ls
+            6 y
+            $ cd /x
+            $ cd /
+            $ cd ..
+            ls
+            $ pwd
+            dir
+            7 .";
+        let (root, warnings) = parse_lenient(&lines(transcript));
+        assert_eq!(
+            kinds(&warnings),
+            [
+                (3, WarningKind::NoSuchDirectory("/a".to_string())),
+                (6, WarningKind::NotADirectory("/x".to_string())),
+                (8, WarningKind::AboveRoot),
+                (9, WarningKind::Malformed("ls".to_string())),
+                (10, WarningKind::UnknownCommand("$ pwd".to_string())),
+                (11, WarningKind::Malformed("dir".to_string())),
+                (12, WarningKind::Malformed("7 .".to_string())),
+            ]
+        );
+        assert_eq!(root.size(), 11);
+        let error = parse_strict(&lines(transcript)).unwrap_err();
+        assert_eq!(error.to_string(), "line 3: no such directory: /a");
+
+        // a listing of / that comes later has to have a in it too
+        let transcript = "$ cd a
+            $ ls
+            5 x
+            $ cd /
+            $ ls
+            10 b";
+        let (root, warnings) = parse_lenient(&lines(transcript));
+        assert_eq!(
+            kinds(&warnings),
+            [(
+                5,
+                WarningKind::ConflictingListing {
+                    path: "/".to_string(),
+                    name: "a".to_string()
+                }
+            )]
+        );
+        // and what's known of a isn't thrown away
+        assert_eq!(root.size(), 15);
+        assert!(root.listed);
+        assert_eq!(parse_strict(&lines(transcript)).unwrap_err().line, 5);
+    }
+
+    #[test]
+    fn test_output_without_listing() {
+        let (_, warnings) = parse_lenient(&lines("$ cd /\n10 b"));
+        assert_eq!(
+            kinds(&warnings),
+            [(2, WarningKind::OutputWithoutListing("10 b".to_string()))]
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use std::ops::Index;
+
+use crate::fs::{Dir, Entry};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    File,
+    Dir,
+}
+
+/// A file or directory. `size` is the file's size, or everything below the
+/// directory added up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub kind: Kind,
+    pub size: usize,
+    pub parent: Option<NodeId>,
+    // sorted by name
+    pub children: Vec<NodeId>,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        self.kind == Kind::Dir
+    }
+}
+
+/// The filesystem with every node in one `Vec`, pointing at each other by
+/// index. It doesn't change once built, so directory sizes are worked out
+/// once, up front.
+#[derive(Debug, Clone)]
+pub struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    pub fn new(root: &Dir) -> Self {
+        let mut tree = Tree { nodes: Vec::new() };
+        tree.add_dir("/", None, root);
+        tree
+    }
+
+    fn add(&mut self, name: &str, kind: Kind, size: usize, parent: Option<NodeId>) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            name: name.to_string(),
+            kind,
+            size,
+            parent,
+            children: Vec::new(),
+        });
+        id
+    }
+
+    fn add_dir(&mut self, name: &str, parent: Option<NodeId>, dir: &Dir) -> NodeId {
+        let id = self.add(name, Kind::Dir, 0, parent);
+        let mut size = 0;
+        for (name, entry) in &dir.entries {
+            let child = match entry {
+                Entry::File(size) => self.add(name, Kind::File, *size, Some(id)),
+                Entry::Dir(dir) => self.add_dir(name, Some(id), dir),
+            };
+            size += self.nodes[child.0].size;
+            self.nodes[id.0].children.push(child);
+        }
+        self.nodes[id.0].size = size;
+        id
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The node at an absolute path like `/a/e`.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        let rest = path.strip_prefix('/')?;
+        let mut id = self.root();
+        for name in rest.split('/').filter(|name| !name.is_empty()) {
+            let children = &self[id].children;
+            let found = children.binary_search_by(|&child| self[child].name.as_str().cmp(name));
+            id = children[found.ok()?];
+        }
+        Some(id)
+    }
+
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = id;
+        while let Some(parent) = self[node].parent {
+            names.push(self[node].name.as_str());
+            node = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Every node, parents before their children, with its depth below the
+    /// root.
+    pub fn walk(&self) -> Walk<'_> {
+        self.walk_from(self.root())
+    }
+
+    pub fn walk_from(&self, id: NodeId) -> Walk<'_> {
+        Walk {
+            tree: self,
+            stack: vec![(id, 0)],
+        }
+    }
+
+    /// Like `du -d depth`: the directories at most `depth` levels below
+    /// `path`, each after the directories inside it, or `None` if there's no
+    /// such directory.
+    pub fn du(&self, path: &str, depth: usize) -> Option<Vec<NodeId>> {
+        let id = self.lookup(path).filter(|&id| self[id].is_dir())?;
+        let mut dirs = Vec::new();
+        self.du_into(id, depth, &mut dirs);
+        Some(dirs)
+    }
+
+    fn du_into(&self, id: NodeId, depth: usize, dirs: &mut Vec<NodeId>) {
+        if depth > 0 {
+            for &child in &self[id].children {
+                if self[child].is_dir() {
+                    self.du_into(child, depth - 1, dirs);
+                }
+            }
+        }
+        dirs.push(id);
+    }
+
+    /// Every node `predicate` holds for, in the order `walk` goes.
+    pub fn find<'a, P>(&'a self, predicate: P) -> impl Iterator<Item = &'a Node> + 'a
+    where
+        P: Fn(&Node) -> bool + 'a,
+    {
+        self.walk()
+            .map(|(id, _)| &self[id])
+            .filter(move |node| predicate(node))
+    }
+}
+
+impl Index<NodeId> for Tree {
+    type Output = Node;
+
+    fn index(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+}
+
+pub struct Walk<'a> {
+    tree: &'a Tree,
+    stack: Vec<(NodeId, usize)>,
+}
+
+impl Iterator for Walk<'_> {
+    type Item = (NodeId, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, depth) = self.stack.pop()?;
+        let children = &self.tree[id].children;
+        self.stack
+            .extend(children.iter().rev().map(|&child| (child, depth + 1)));
+        Some((id, depth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell::parse_strict;
+
+    fn example() -> Tree {
+        let lines: Vec<String> = include_str!("../example.txt")
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+        Tree::new(&parse_strict(&lines).unwrap())
+    }
+
+    #[test]
+    fn test_lookup() {
+        let tree = example();
+        assert_eq!(tree.len(), 14);
+        assert_eq!(tree.lookup("/"), Some(tree.root()));
+        let e = tree.lookup("/a/e").unwrap();
+        assert_eq!(tree[e].size, 584);
+        assert_eq!(tree.path(e), "/a/e");
+        assert_eq!(tree.lookup("/a/e/"), Some(e));
+        assert_eq!(tree[tree.lookup("/a").unwrap()].size, 94853);
+        assert_eq!(tree[tree.lookup("/d").unwrap()].size, 24933642);
+        assert_eq!(tree[tree.root()].size, 48381165);
+        assert_eq!(tree[tree.lookup("/d/k").unwrap()].kind, Kind::File);
+        assert_eq!(tree.lookup("/a/x"), None);
+        assert_eq!(tree.lookup("/b.txt/x"), None);
+        assert_eq!(tree.lookup("a"), None);
+    }
+
+    #[test]
+    fn test_walk() {
+        let tree = example();
+        let walked: Vec<(String, usize)> = tree
+            .walk()
+            .map(|(id, depth)| (tree.path(id), depth))
+            .collect();
+        let expected = [
+            ("/", 0),
+            ("/a", 1),
+            ("/a/e", 2),
+            ("/a/e/i", 3),
+            ("/a/f", 2),
+            ("/a/g", 2),
+            ("/a/h.lst", 2),
+            ("/b.txt", 1),
+            ("/c.dat", 1),
+            ("/d", 1),
+            ("/d/d.ext", 2),
+            ("/d/d.log", 2),
+            ("/d/j", 2),
+            ("/d/k", 2),
+        ];
+        let expected: Vec<(String, usize)> = expected
+            .iter()
+            .map(|&(path, depth)| (path.to_string(), depth))
+            .collect();
+        assert_eq!(walked, expected);
+    }
+
+    #[test]
+    fn test_du() {
+        let tree = example();
+        let du = |path, depth| -> Vec<String> {
+            let dirs = tree.du(path, depth).unwrap();
+            dirs.into_iter().map(|id| tree.path(id)).collect()
+        };
+        assert_eq!(du("/", 0), ["/"]);
+        assert_eq!(du("/", 1), ["/a", "/d", "/"]);
+        assert_eq!(du("/", 5), ["/a/e", "/a", "/d", "/"]);
+        assert_eq!(du("/a", 1), ["/a/e", "/a"]);
+        assert_eq!(tree.du("/b.txt", 1), None);
+        assert_eq!(tree.du("/x", 1), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let tree = example();
+        let big: Vec<&str> = tree
+            .find(|node| node.size > 8_000_000)
+            .map(|node| node.name.as_str())
+            .collect();
+        assert_eq!(big, ["/", "b.txt", "c.dat", "d", "d.log"]);
+        assert_eq!(tree.find(|node| node.is_dir()).count(), 4);
+    }
+}
@@ -1,6 +1,4 @@
-use std::io::{self, BufRead};
-
-fn parse_input(lines: &[String]) -> Vec<Vec<u32>> {
+pub fn parse_input(lines: &[String]) -> Vec<Vec<u32>> {
     let mut map: Vec<Vec<u32>> = vec![];
     for line in lines {
         map.push(line.chars().map(|c| c.to_digit(10).unwrap()).collect())
@@ -8,7 +6,7 @@ fn parse_input(lines: &[String]) -> Vec<Vec<u32>> {
     map
 }
 
-fn is_visible(map: &[Vec<u32>], x: usize, y: usize) -> bool {
+pub fn is_visible(map: &[Vec<u32>], x: usize, y: usize) -> bool {
     let v = map[y][x];
     let height = map.len();
     let width = map[0].len();
@@ -31,16 +29,16 @@ fn is_visible(map: &[Vec<u32>], x: usize, y: usize) -> bool {
     }
 
     let mut left = true;
-    for tx in 0..x {
-        if map[y][tx] >= v {
+    for tree in map[y].iter().take(x) {
+        if *tree >= v {
             left = false;
             break;
         }
     }
 
     let mut right = true;
-    for tx in x + 1..width {
-        if map[y][tx] >= v {
+    for tree in map[y].iter().take(width).skip(x + 1) {
+        if *tree >= v {
             right = false;
             break;
         }
@@ -49,7 +47,7 @@ fn is_visible(map: &[Vec<u32>], x: usize, y: usize) -> bool {
     up || down || left || right
 }
 
-fn solution(map: &Vec<Vec<u32>>) -> usize {
+pub fn solution(map: &[Vec<u32>]) -> usize {
     let height = map.len();
     let width = map[0].len();
     let mut count = 0;
@@ -63,10 +61,8 @@ fn solution(map: &Vec<Vec<u32>>) -> usize {
     count
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solution(&parse_input(&lines)));
+pub fn solve(lines: &[String]) -> usize {
+    solution(&parse_input(lines))
 }
 
 #[cfg(test)]
@@ -1,6 +1,4 @@
-use std::io::{self, BufRead};
-
-fn parse_input(lines: &[String]) -> Vec<Vec<u32>> {
+pub fn parse_input(lines: &[String]) -> Vec<Vec<u32>> {
     let mut map: Vec<Vec<u32>> = vec![];
     for line in lines {
         map.push(line.chars().map(|c| c.to_digit(10).unwrap()).collect())
@@ -8,7 +6,7 @@ fn parse_input(lines: &[String]) -> Vec<Vec<u32>> {
     map
 }
 
-fn scenic_score(map: &[Vec<u32>], x: usize, y: usize) -> usize {
+pub fn scenic_score(map: &[Vec<u32>], x: usize, y: usize) -> usize {
     let v = map[y][x];
     // let height = map.len();
     let width = map[0].len();
@@ -38,9 +36,9 @@ fn scenic_score(map: &[Vec<u32>], x: usize, y: usize) -> usize {
     }
 
     let mut right = 0;
-    for tx in x + 1..width {
+    for tree in map[y].iter().take(width).skip(x + 1) {
         right += 1;
-        if map[y][tx] >= v {
+        if *tree >= v {
             break;
         }
     }
@@ -48,7 +46,7 @@ fn scenic_score(map: &[Vec<u32>], x: usize, y: usize) -> usize {
     up * down * left * right
 }
 
-fn solution(map: &Vec<Vec<u32>>) -> usize {
+pub fn solution(map: &[Vec<u32>]) -> usize {
     let height = map.len();
     let width = map[0].len();
     let mut score = 0;
@@ -60,10 +58,8 @@ fn solution(map: &Vec<Vec<u32>>) -> usize {
     score
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solution(&parse_input(&lines)));
+pub fn solve(lines: &[String]) -> usize {
+    solution(&parse_input(lines))
 }
 
 #[cfg(test)]
@@ -0,0 +1,10 @@
+use std::io::{self, BufRead};
+
+use day09::{part1, part2};
+
+fn main() {
+    let lines = io::stdin().lock().lines();
+    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
+    println!("{}", part1::solve(&lines));
+    println!("{}", part2::solve(&lines));
+}
@@ -1,7 +1,7 @@
-use std::{io::{self, BufRead}, collections::HashSet};
+use std::collections::HashSet;
 
 #[derive(Debug)]
-enum Direction {
+pub enum Direction {
     Up,
     Right,
     Down,
@@ -9,13 +9,13 @@ enum Direction {
 }
 
 #[derive(PartialEq, Debug, Clone)]
-struct Point {
-    x: i32,
-    y: i32,
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl Point {
-    fn walk(&mut self, direction: &Direction) {
+    pub fn walk(&mut self, direction: &Direction) {
         match direction {
             Direction::Up => { self.y += 1 }
             Direction::Right => { self.x += 1 },
@@ -24,7 +24,7 @@ impl Point {
         }
     }
 
-    fn follow(&mut self, other: &Point) {
+    pub fn follow(&mut self, other: &Point) {
         let dx = other.x - self.x;
         let dy = other.y - self.y;
         if i32::abs(dx) > 1 || i32::abs(dy) > 1 {
@@ -34,7 +34,7 @@ impl Point {
     }
 }
 
-fn parse_input(lines: &[String]) -> Vec<(Direction, u32)> {
+pub fn parse_input(lines: &[String]) -> Vec<(Direction, u32)> {
     let mut steps: Vec<(Direction, u32)> = vec![];
     for line in lines {
         let parts: Vec<&str> = line.split_whitespace().collect();
@@ -52,7 +52,7 @@ fn parse_input(lines: &[String]) -> Vec<(Direction, u32)> {
 }
 
 
-fn solution(steps: &Vec<(Direction, u32)>) -> usize {
+pub fn solution(steps: &Vec<(Direction, u32)>) -> usize {
     let rope_length = 10;
     let mut knots: Vec<Point> = vec![Point { x:0, y:0 }; rope_length];
     let mut visited: HashSet<(i32, i32)> = HashSet::new();
@@ -71,16 +71,10 @@ fn solution(steps: &Vec<(Direction, u32)>) -> usize {
     visited.len()
 }
 
-fn solve(lines: &[String]) -> usize {
+pub fn solve(lines: &[String]) -> usize {
     solution(&parse_input(lines))
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,12 @@
+use std::io::{self, BufRead};
+
+use day10::{part1, part2};
+
+fn main() {
+    let lines = io::stdin().lock().lines();
+    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
+    println!("{}", part1::solve(&lines));
+    for line in part2::solve(&lines) {
+        println!("{}", line);
+    }
+}
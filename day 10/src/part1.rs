@@ -0,0 +1,63 @@
+use common::parse::{int, parse_line};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    sequence::preceded,
+    IResult,
+};
+
+#[derive(Clone)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+pub fn instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::Noop, tag("noop")),
+        map(preceded(tag("addx "), int), Instruction::Addx),
+    ))(input)
+}
+
+pub fn parse_input(lines: &[String]) -> Vec<Instruction> {
+    let mut instructions: Vec<Instruction> = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        if !line.is_empty() {
+            instructions.push(parse_line(instruction, i, line).unwrap_or_else(|e| panic!("{}", e)));
+        }
+    }
+    instructions
+}
+
+pub fn solution(instructions: &Vec<Instruction>) -> i32 {
+    let read_points = [20, 60, 100, 140, 180, 220];
+    let mut x = 1;
+    let mut cycle = 1;
+    let mut strength = 0;
+
+    for instr in instructions {
+        match instr {
+            Instruction::Noop => {
+                if read_points.contains(&cycle) {
+                    strength += cycle * x;
+                }
+                cycle += 1;
+            }
+            Instruction::Addx(value) => {
+                for _ in 0..2 {
+                    if read_points.contains(&cycle) {
+                        strength += cycle * x;
+                    }
+                    cycle += 1;
+                }
+                x += value;
+            }
+        }
+    }
+    strength
+}
+
+pub fn solve(lines: &[String]) -> i32 {
+    solution(&parse_input(lines))
+}
@@ -0,0 +1,70 @@
+use common::parse::{int, parse_line};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    sequence::preceded,
+    IResult,
+};
+
+#[derive(Clone)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+pub fn instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::Noop, tag("noop")),
+        map(preceded(tag("addx "), int), Instruction::Addx),
+    ))(input)
+}
+
+pub fn parse_input(lines: &[String]) -> Vec<Instruction> {
+    let mut instructions: Vec<Instruction> = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        if !line.is_empty() {
+            instructions.push(parse_line(instruction, i, line).unwrap_or_else(|e| panic!("{}", e)));
+        }
+    }
+    instructions
+}
+
+pub fn render(x: i32, cycle: i32, scanlines: &mut Vec<String>) {
+    let pixel = (cycle - 1) % 40;
+    if pixel == 0 {
+        scanlines.push("".to_string());
+    }
+    if i32::abs(x - pixel) <= 1 {
+        scanlines.last_mut().unwrap().push('#');
+    } else {
+        scanlines.last_mut().unwrap().push('.');
+    }
+}
+
+pub fn solution(instructions: &Vec<Instruction>) -> Vec<String>{
+    let mut x = 1;
+    let mut cycle = 1;
+    let mut scanlines: Vec<String> = vec![];
+
+    for instr in instructions {
+        match instr {
+            Instruction::Noop => {
+                render(x, cycle, &mut scanlines);
+                cycle += 1;
+            }
+            Instruction::Addx(value) => {
+                for _ in 0..2 {
+                    render(x, cycle, &mut scanlines);
+                    cycle += 1;
+                }
+                x += value;
+            }
+        }
+    }
+    scanlines
+}
+
+pub fn solve(lines: &[String]) -> Vec<String> {
+    solution(&parse_input(lines))
+}
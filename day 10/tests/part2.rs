@@ -0,0 +1,44 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use day10::part2::*;
+
+#[test]
+fn test_example() {
+    let reader = BufReader::new(File::open("example.txt").unwrap());
+
+    let lines: Vec<String> = reader
+        .lines()
+        .map(|x| x.unwrap().trim().to_string())
+        .filter(|x| !x.is_empty())
+        .collect();
+    assert_eq!(solve(&lines), vec![
+        "##..##..##..##..##..##..##..##..##..##..".to_string(),
+        "###...###...###...###...###...###...###.".to_string(),
+        "####....####....####....####....####....".to_string(),
+        "#####.....#####.....#####.....#####.....".to_string(),
+        "######......######......######......####".to_string(),
+        "#######.......#######.......#######.....".to_string(),
+    ]);
+}
+
+#[test]
+fn test_input() {
+    let reader = BufReader::new(File::open("input.txt").unwrap());
+
+    let lines: Vec<String> = reader
+        .lines()
+        .map(|x| x.unwrap().trim().to_string())
+        .filter(|x| !x.is_empty())
+        .collect();
+    assert_eq!(solve(&lines), vec![
+        "####.####.####.###..###...##..#..#.#....".to_string(),
+        "#.......#.#....#..#.#..#.#..#.#.#..#....".to_string(),
+        "###....#..###..#..#.#..#.#..#.##...#....".to_string(),
+        "#.....#...#....###..###..####.#.#..#....".to_string(),
+        "#....#....#....#....#.#..#..#.#.#..#....".to_string(),
+        "####.####.#....#....#..#.#..#.#..#.####.".to_string(),
+    ]);
+}
@@ -0,0 +1,137 @@
+use std::collections::VecDeque;
+
+use common::parse::{blocks_by, comma_list, field, int, Block, ParseError};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{map, value},
+    sequence::{delimited, preceded},
+    IResult,
+};
+
+#[derive(Debug, Clone)]
+pub enum MonkeyOp {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+#[derive(Debug)]
+pub struct Monkey {
+    pub items: VecDeque<u64>,
+    pub op: MonkeyOp,
+    pub divisibe_by: u64,
+    pub if_true: usize,
+    pub if_false: usize,
+    pub inspections: u64,
+}
+
+impl Monkey {
+    pub fn new() -> Monkey {
+        Monkey {
+            items: VecDeque::new(),
+            op: MonkeyOp::Square,
+            divisibe_by: 1,
+            if_true: 0,
+            if_false: 0,
+            inspections: 0,
+        }
+    }
+}
+
+impl Default for Monkey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn operation(input: &str) -> IResult<&str, MonkeyOp> {
+    preceded(
+        tag("new = old "),
+        alt((
+            value(MonkeyOp::Square, tag("* old")),
+            map(preceded(tag("+ "), int), MonkeyOp::Add),
+            map(preceded(tag("* "), int), MonkeyOp::Mul),
+        )),
+    )(input)
+}
+
+fn throw_to(input: &str) -> IResult<&str, usize> {
+    preceded(tag("throw to monkey "), int)(input)
+}
+
+pub fn parse_monkey(block: &Block) -> Result<Monkey, ParseError> {
+    block.parse_line(0, delimited(tag("Monkey "), int::<usize>, char(':')))?;
+    Ok(Monkey {
+        items: block
+            .parse_line(1, field("Starting items", comma_list(int)))?
+            .into(),
+        op: block.parse_line(2, field("Operation", operation))?,
+        divisibe_by: block.parse_line(3, field("Test", preceded(tag("divisible by "), int)))?,
+        if_true: block.parse_line(4, field("If true", throw_to))?,
+        if_false: block.parse_line(5, field("If false", throw_to))?,
+        inspections: 0,
+    })
+}
+
+// each monkey starts with its `Monkey n:` line, blank lines between them or not
+pub fn parse_input(lines: &[String]) -> Vec<Monkey> {
+    blocks_by(lines, |line| line.starts_with("Monkey "))
+        .iter()
+        .map(|block| parse_monkey(block).unwrap_or_else(|e| panic!("{}", e)))
+        .collect()
+}
+
+pub fn play_round(monkeys: &mut [Monkey], supermodulo: u64) {
+    for i in 0..monkeys.len() {
+        loop {
+            let mut item;
+            let next_monkey;
+            {
+                let monkey = &mut monkeys[i];
+                if monkey.items.is_empty() {
+                    break;
+                }
+                // monkey grabs item, increase worry
+                item = monkey.items.pop_front().unwrap();
+                monkey.inspections += 1;
+                match monkey.op {
+                    MonkeyOp::Add(value) => item = item % supermodulo + value,
+                    MonkeyOp::Mul(value) => item = item % supermodulo * value,
+                    MonkeyOp::Square => item = item % supermodulo * item,
+                }
+
+                // part 2, worry never decreases
+                item %= supermodulo;
+
+                // perform test
+                if item % monkey.divisibe_by == 0 {
+                    next_monkey = monkey.if_true;
+                } else {
+                    next_monkey = monkey.if_false;
+                }
+            }
+            let m = &mut monkeys[next_monkey];
+            m.items.push_back(item);
+        }
+    }
+}
+
+pub fn solution(monkeys: &mut [Monkey]) -> u64 {
+    let supermodulo: u64 = monkeys.iter().map(|m| m.divisibe_by).product();
+    for _ in 0..10000 {
+        play_round(monkeys, supermodulo);
+    }
+    monkeys.sort_by_key(|monkey| monkey.inspections);
+    monkeys
+        .iter()
+        .rev()
+        .take(2)
+        .map(|monkey| monkey.inspections)
+        .product()
+}
+
+pub fn solve(lines: &[String]) -> u64 {
+    solution(&mut parse_input(lines))
+}
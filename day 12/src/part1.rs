@@ -1,14 +1,14 @@
-use std::io::{self, BufRead};
+use std::collections::HashSet;
 
-use pathfinding::prelude::dijkstra;
+use common::search::{astar, Stats};
 
-struct Input {
-    map: Vec<Vec<u32>>,
-    start: Pos,
-    end: Pos,
+pub struct Input {
+    pub map: Vec<Vec<u32>>,
+    pub start: Pos,
+    pub end: Pos,
 }
 
-fn parse_input(lines: &[String]) -> Input {
+pub fn parse_input(lines: &[String]) -> Input {
     let mut input = Input {
         map: vec![],
         start: Pos { x: 0, y: 0 },
@@ -43,12 +43,12 @@ fn parse_input(lines: &[String]) -> Input {
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
-struct Pos {
-    x: i32,
-    y: i32,
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
 }
 
-fn successors(pos: &Pos, map: &[Vec<u32>]) -> Vec<(Pos, usize)> {
+pub fn successors(pos: &Pos, map: &[Vec<u32>]) -> Vec<(Pos, usize)> {
     let height = map.len() as i32;
     let width = map[0].len() as i32;
     let mut v: Vec<(Pos, usize)> = vec![];
@@ -80,43 +80,19 @@ fn successors(pos: &Pos, map: &[Vec<u32>]) -> Vec<(Pos, usize)> {
     v
 }
 
-fn solution(input: Input) -> usize {
-    let result = dijkstra(
-        &input.start,
+pub fn solution(input: Input) -> usize {
+    let end = &input.end;
+    let result = astar(
+        input.start.clone(),
         |p| successors(p, &input.map),
-        |p| *p == input.end,
+        |p| (p.x.abs_diff(end.x) + p.y.abs_diff(end.y)) as usize,
+        |p| p == end,
+        &mut HashSet::new(),
+        &mut Stats::default(),
     );
     result.expect("no path found").1
 }
 
-fn solve(lines: &[String]) -> usize {
+pub fn solve(lines: &[String]) -> usize {
     solution(parse_input(lines))
 }
-
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    // for line in solve(&lines) {
-    //     println!("{}", line);
-    // }
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    #[test]
-    fn test_example() {
-        let reader = BufReader::new(File::open("example.txt").unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines), 31);
-    }
-}
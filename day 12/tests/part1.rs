@@ -0,0 +1,27 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use day12::part1::*;
+
+fn test_file(filename: &str, solution: &str) {
+    let reader = BufReader::new(File::open(filename).unwrap());
+
+    let lines: Vec<String> = reader
+        .lines()
+        .map(|x| x.unwrap().trim().to_string())
+        .filter(|x| !x.is_empty())
+        .collect();
+    assert_eq!(solve(&lines).to_string(), solution);
+}
+
+#[test]
+fn test_example() {
+    test_file("example.txt", "31");
+}
+
+#[test]
+fn test_input() {
+    test_file("input.txt", "420");
+}
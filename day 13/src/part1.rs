@@ -0,0 +1,119 @@
+use common::parse::{blocks, int};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::space0;
+use nom::combinator::map;
+use nom::multi::separated_list0;
+use nom::sequence::delimited;
+use nom::IResult;
+
+#[derive(Debug, Clone)]
+pub enum Item {
+    Number(u32),
+    List(Vec<Item>),
+}
+
+pub fn number(input: &str) -> IResult<&str, Item> {
+    map(int, Item::Number)(input)
+}
+
+// spaces are allowed around brackets and commas
+pub fn list(input: &str) -> IResult<&str, Item> {
+    let open = delimited(space0, tag("["), space0);
+    let close = delimited(space0, tag("]"), space0);
+    let result = delimited(open, items, close)(input);
+    result.map(|(input, items)| (input, Item::List(items)))
+}
+
+pub fn item(input: &str) -> IResult<&str, Item> {
+    alt((number, list))(input)
+}
+
+pub fn items(input: &str) -> IResult<&str, Vec<Item>> {
+    separated_list0(delimited(space0, tag(","), space0), item)(input)
+}
+
+#[derive(Debug)]
+pub struct PacketPair(pub Item, pub Item);
+
+pub fn parse_input(lines: &[String]) -> Vec<PacketPair> {
+    blocks(lines)
+        .iter()
+        .map(|block| {
+            PacketPair(
+                block.parse_line(0, list).unwrap_or_else(|e| panic!("{}", e)),
+                block.parse_line(1, list).unwrap_or_else(|e| panic!("{}", e)),
+            )
+        })
+        .collect()
+}
+
+pub fn compare_nums(left: &u32, right: &u32) -> i32 {
+    if left < right {
+        return 1;
+    };
+    if right < left {
+        return -1;
+    };
+    0
+}
+
+pub fn compare_lists(left: &[Item], right: &[Item]) -> i32 {
+    // first, compare each item
+    for i in 0..std::cmp::min(left.len(), right.len()) {
+        let c = compare_items(&left[i], &right[i]);
+        if c != 0 {
+            return c;
+        }
+    }
+    // if we reached this point, we compare list lengths
+    if left.len() < right.len() { return 1; }
+    if left.len() > right.len() { return -1; }
+    0
+}
+
+pub fn compare_items(left: &Item, right: &Item) -> i32 {
+    match left {
+        Item::Number(l_num) => {
+            match right {
+                Item::Number(r_num) => {
+                    compare_nums(l_num, r_num)
+                }
+                Item::List(r_list) => {
+                    let l_list = vec![left.clone()];
+                    compare_lists(&l_list, r_list)
+                }
+            }
+        }
+        Item::List(l_list) => {
+            match right {
+                Item::Number(_) => {
+                    let r_list = vec![right.clone()];
+                    compare_lists(l_list, &r_list)
+                }
+                Item::List(r_list) => {
+                    compare_lists(l_list, r_list)
+                }
+            }
+        }
+    }
+}
+
+pub fn compare_pairs(pair: &PacketPair) -> i32 {
+    compare_items(&pair.0, &pair.1)
+}
+
+pub fn solution(pairs: Vec<PacketPair>) -> usize {
+    let mut sum = 0;
+    for (i, pair) in pairs.iter().enumerate() {
+        if compare_pairs(pair) > 0 {
+            // pair index starts at 1
+            sum += i + 1;
+        }
+    }
+    sum
+}
+
+pub fn solve(lines: &[String]) -> usize {
+    solution(parse_input(lines))
+}
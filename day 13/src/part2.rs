@@ -1,50 +1,52 @@
 use std::cmp::Ordering;
-use std::io::{self, BufRead};
 
+use common::parse::{int, parse_line};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::u32;
+use nom::character::complete::space0;
+use nom::combinator::map;
 use nom::multi::separated_list0;
 use nom::sequence::delimited;
 use nom::IResult;
 
 #[derive(Debug, Clone, PartialEq)]
-enum Item {
+pub enum Item {
     Number(u32),
     List(Vec<Item>),
 }
 
-fn number(input: &str) -> IResult<&str, Item> {
-    let result = u32(input);
-    result.map(|(input, num)| (input, Item::Number(num)))
+pub fn number(input: &str) -> IResult<&str, Item> {
+    map(int, Item::Number)(input)
 }
 
-fn list(input: &str) -> IResult<&str, Item> {
-    let result = delimited(tag("["), items, tag("]"))(input);
+// spaces are allowed around brackets and commas
+pub fn list(input: &str) -> IResult<&str, Item> {
+    let open = delimited(space0, tag("["), space0);
+    let close = delimited(space0, tag("]"), space0);
+    let result = delimited(open, items, close)(input);
     result.map(|(input, items)| (input, Item::List(items)))
 }
 
-fn item(input: &str) -> IResult<&str, Item> {
+pub fn item(input: &str) -> IResult<&str, Item> {
     alt((number, list))(input)
 }
 
-fn items(input: &str) -> IResult<&str, Vec<Item>> {
-    separated_list0(tag(","), item)(input)
+pub fn items(input: &str) -> IResult<&str, Vec<Item>> {
+    separated_list0(delimited(space0, tag(","), space0), item)(input)
 }
 
-fn parse_input(lines: &[String]) -> Vec<Item> {
+pub fn parse_input(lines: &[String]) -> Vec<Item> {
     let mut result = vec![];
-    for line in lines {
-        let l = line.replace(' ', "");
-        if l.is_empty() {
+    for (i, line) in lines.iter().enumerate() {
+        if line.is_empty() {
             continue;
         };
-        result.push(list(&l).unwrap().1);
+        result.push(parse_line(list, i, line).unwrap_or_else(|e| panic!("{}", e)));
     }
     result
 }
 
-fn compare_nums(left: &u32, right: &u32) -> Ordering {
+pub fn compare_nums(left: &u32, right: &u32) -> Ordering {
     if left < right {
         return Ordering::Less;
     };
@@ -54,7 +56,7 @@ fn compare_nums(left: &u32, right: &u32) -> Ordering {
     Ordering::Equal
 }
 
-fn compare_lists(left: &Vec<Item>, right: &Vec<Item>) -> Ordering {
+pub fn compare_lists(left: &[Item], right: &[Item]) -> Ordering {
     // first, compare each item
     for i in 0..std::cmp::min(left.len(), right.len()) {
         let c = compare_items(&left[i], &right[i]);
@@ -72,7 +74,7 @@ fn compare_lists(left: &Vec<Item>, right: &Vec<Item>) -> Ordering {
     Ordering::Equal
 }
 
-fn compare_items(left: &Item, right: &Item) -> Ordering {
+pub fn compare_items(left: &Item, right: &Item) -> Ordering {
     match left {
         Item::Number(l_num) => match right {
             Item::Number(r_num) => compare_nums(l_num, r_num),
@@ -91,7 +93,7 @@ fn compare_items(left: &Item, right: &Item) -> Ordering {
     }
 }
 
-fn solution(mut items: Vec<Item>) -> usize {
+pub fn solution(mut items: Vec<Item>) -> usize {
     let div2 = list("[[2]]").unwrap().1;
     let div6 = list("[[6]]").unwrap().1;
     items.push(div2);
@@ -109,40 +111,18 @@ fn solution(mut items: Vec<Item>) -> usize {
     result
 }
 
-fn solve(lines: &[String]) -> usize {
+pub fn solve(lines: &[String]) -> usize {
     solution(parse_input(lines))
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::BufReader};
-
     use super::*;
 
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "140");
-    }
-
     #[test]
-    fn test_input() {
-        test_file("input.txt", "24805");
+    fn test_spaces() {
+        let packet = |line| parse_line(list, 0, line).unwrap();
+        assert_eq!(packet(" [ 1, [2 ,3] , [ ] ] "), packet("[1,[2,3],[]]"));
+        assert!(parse_line(list, 0, "[1,,2]").is_err());
     }
 }
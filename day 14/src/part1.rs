@@ -1,18 +1,15 @@
-use std::{
-    io::{self, BufRead},
-    ops::{Add, Sub},
-};
+use std::ops::{Add, Sub};
 
 #[derive(Clone, Copy, Debug)]
-struct Point {
-    x: i32,
-    y: i32,
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Dir {
-    x: i32,
-    y: i32,
+pub struct Dir {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl Sub for Point {
@@ -37,13 +34,13 @@ impl Add<&Dir> for Point {
     }
 }
 
-struct Cave {
-    map: Vec<String>,
-    origin: Point,
+pub struct Cave {
+    pub map: Vec<String>,
+    pub origin: Point,
 }
 
 impl Cave {
-    fn set(&mut self, p: &Point, c: &str) {
+    pub fn set(&mut self, p: &Point, c: &str) {
         let _p = *p - self.origin;
         let ux = _p.x as usize;
         let uy = _p.y as usize;
@@ -57,7 +54,7 @@ impl Cave {
     //     self.map[uy].get(ux..ux + 1).unwrap()
     // }
 
-    fn is_void_at(&self, p: &Point) -> bool {
+    pub fn is_void_at(&self, p: &Point) -> bool {
         // dbg!(p);
         let _p = *p - self.origin;
         // dbg!(_p);
@@ -77,7 +74,7 @@ impl Cave {
         false
     }
 
-    fn is_empty_at(&self, p: &Point) -> bool {
+    pub fn is_empty_at(&self, p: &Point) -> bool {
         if self.is_void_at(p) {
             return true;
         }
@@ -89,7 +86,7 @@ impl Cave {
     }
 }
 
-fn parse_input(lines: &[String]) -> Cave {
+pub fn parse_input(lines: &[String]) -> Cave {
     let mut max_x = 500;
     let mut max_y = 0;
     let mut min_x = 10000;
@@ -149,7 +146,7 @@ fn parse_input(lines: &[String]) -> Cave {
     cave
 }
 
-fn fall(cave: &mut Cave) -> Option<Point> {
+pub fn fall(cave: &mut Cave) -> Option<Point> {
     let mut ball = Point { x: 500, y: 0 };
 
     let down = Dir { x: 0, y: 1 };
@@ -183,52 +180,15 @@ fn fall(cave: &mut Cave) -> Option<Point> {
     }
 }
 
-fn solution(cave: &mut Cave) -> usize {
+pub fn solution(cave: &mut Cave) -> usize {
     let mut c: usize = 0;
     while let Some(p) = fall(cave) {
         cave.set(&p, ".");
         c += 1;
     }
-    for line in &cave.map {
-        println!("{}", line);
-    }
     c
 }
 
-fn solve(lines: &[String]) -> usize {
+pub fn solve(lines: &[String]) -> usize {
     solution(&mut parse_input(lines))
 }
-
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "24");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "805");
-    }
-}
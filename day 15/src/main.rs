@@ -0,0 +1,15 @@
+use std::{
+    env,
+    io::{self, BufRead},
+};
+
+use day15::{part1, part2};
+
+fn main() {
+    let lines = io::stdin().lock().lines();
+    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
+    let args: Vec<String> = env::args().collect();
+    let row = args[1].parse().unwrap();
+    println!("{}", part1::solve(&lines, row));
+    println!("{}", part2::solve(&lines, row));
+}
@@ -1,27 +1,31 @@
 use std::{
     cmp::{max, min},
     collections::HashSet,
-    env,
-    io::{self, BufRead},
     ops::{Add, Sub},
 };
 
-use regex::Regex;
+use common::parse::{coord, parse_lines};
+use nom::{
+    bytes::complete::tag,
+    combinator::map,
+    sequence::{pair, preceded},
+    IResult,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Dir {
-    x: i32,
-    y: i32,
+pub struct Dir {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl Point {
-    fn manhattan_distance(&self, other: &Point) -> i32 {
+    pub fn manhattan_distance(&self, other: &Point) -> i32 {
         i32::abs(self.x - other.x) + i32::abs(self.y - other.y)
     }
 }
@@ -49,19 +53,19 @@ impl Add<&Dir> for Point {
 }
 
 #[derive(Debug)]
-struct Sensor {
-    position: Point,
-    beacon: Point,
+pub struct Sensor {
+    pub position: Point,
+    pub beacon: Point,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Line {
-    from: Point,
-    to: Point,
+pub struct Line {
+    pub from: Point,
+    pub to: Point,
 }
 
 impl Line {
-    fn new(from: Point, to: Point) -> Self {
+    pub fn new(from: Point, to: Point) -> Self {
         let w = to.x - from.x;
         let h = to.y - from.y;
         let mut t = to;
@@ -70,7 +74,7 @@ impl Line {
         Self { from, to: t }
     }
 
-    fn m(&self) -> i32 {
+    pub fn m(&self) -> i32 {
         if self.to.x == self.from.x {
             assert!(self.to.y == self.from.y);
             return 1;
@@ -78,11 +82,11 @@ impl Line {
         (self.to.y - self.from.y) / (self.to.x - self.from.x)
     }
 
-    fn n(&self) -> i32 {
+    pub fn n(&self) -> i32 {
         self.from.y - self.m() * self.from.x
     }
 
-    fn from_tuples(from: (i32, i32), to: (i32, i32)) -> Self {
+    pub fn from_tuples(from: (i32, i32), to: (i32, i32)) -> Self {
         let from = Point {
             x: from.0,
             y: from.1,
@@ -91,7 +95,7 @@ impl Line {
         Self::new(from, to)
     }
 
-    fn crosses(&self, other: &Line) -> bool {
+    pub fn crosses(&self, other: &Line) -> bool {
         let ofx_proj = self.m() * other.from.x + self.n();
         let dfx = i32::signum(other.from.y - ofx_proj);
 
@@ -104,7 +108,7 @@ impl Line {
         dfx != dtx
     }
 
-    fn intersection(&self, other: &Line) -> Option<Line> {
+    pub fn intersection(&self, other: &Line) -> Option<Line> {
         let sfx = min(self.from.x, self.to.x);
         let stx = max(self.from.x, self.to.x);
         let ofx = min(other.from.x, other.to.x);
@@ -161,30 +165,27 @@ impl Line {
     }
 }
 
-fn parse_input(lines: &[String]) -> Vec<Sensor> {
-    let mut sensors = vec![];
-    let re = Regex::new(r"Sensor at x=(?P<sx>-?\d+), y=(?P<sy>-?\d+): closest beacon is at x=(?P<bx>-?\d+), y=(?P<by>-?\d+)").unwrap();
-    for line in lines {
-        let caps = re.captures(line).unwrap();
-        let sensor = Sensor {
-            position: Point {
-                x: caps.name("sx").unwrap().as_str().parse().unwrap(),
-                y: caps.name("sy").unwrap().as_str().parse().unwrap(),
-            },
-            beacon: Point {
-                x: caps.name("bx").unwrap().as_str().parse().unwrap(),
-                y: caps.name("by").unwrap().as_str().parse().unwrap(),
-            },
-        };
-        sensors.push(sensor);
-    }
-    sensors
+pub fn sensor(input: &str) -> IResult<&str, Sensor> {
+    map(
+        pair(
+            preceded(tag("Sensor at "), coord),
+            preceded(tag(": closest beacon is at "), coord),
+        ),
+        |((sx, sy), (bx, by))| Sensor {
+            position: Point { x: sx, y: sy },
+            beacon: Point { x: bx, y: by },
+        },
+    )(input)
+}
+
+pub fn parse_input(lines: &[String]) -> Vec<Sensor> {
+    parse_lines(sensor, lines).unwrap()
 }
 
 use gcollections::ops::*;
 use interval::interval_set::*;
 
-fn solution(sensors: Vec<Sensor>, row: i32) -> usize {
+pub fn solution(sensors: Vec<Sensor>, row: i32) -> usize {
     let mut coverage = 0;
     let target_row = row;
     let mut beacons_on_row = HashSet::new();
@@ -203,60 +204,26 @@ fn solution(sensors: Vec<Sensor>, row: i32) -> usize {
             )]
             .to_interval_set();
 
-            println!("adding interval: {}", interval);
             cover = cover.union(&interval);
         }
         if sensor.beacon.y == target_row {
             beacons_on_row.insert(sensor.beacon.x);
         }
     }
-    println!("result:");
-    println!("intervals: {}", cover);
-    println!("beacons on row: {:?}", beacons_on_row);
     for i in cover.iter() {
         coverage += i.upper() - i.lower() + 1;
     }
     coverage as usize - beacons_on_row.len()
 }
 
-fn solve(lines: &[String], row: i32) -> usize {
+pub fn solve(lines: &[String], row: i32) -> usize {
     solution(parse_input(lines), row)
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    let args: Vec<String> = env::args().collect();
-    println!("{}", solve(&lines, args[1].parse().unwrap()));
-}
-
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::BufReader};
-
     use super::*;
 
-    fn test_file(filename: &str, row: i32, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines, row).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", 10, "26");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", 2000000, "5870800");
-    }
-
     #[test]
     fn test_line() {
         let l = Line::new(Point { x: 0, y: 0 }, Point { x: 10, y: 10 });
@@ -0,0 +1,27 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use day15::part1::*;
+
+fn test_file(filename: &str, row: i32, solution: &str) {
+    let reader = BufReader::new(File::open(filename).unwrap());
+
+    let lines: Vec<String> = reader
+        .lines()
+        .map(|x| x.unwrap().trim().to_string())
+        .filter(|x| !x.is_empty())
+        .collect();
+    assert_eq!(solve(&lines, row).to_string(), solution);
+}
+
+#[test]
+fn test_example() {
+    test_file("example.txt", 10, "26");
+}
+
+#[test]
+fn test_input() {
+    test_file("input.txt", 2000000, "5870800");
+}
@@ -0,0 +1,29 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use day15::part2::*;
+
+fn test_file(filename: &str, row: i32, solution: &str) {
+    let reader = BufReader::new(File::open(filename).unwrap());
+
+    let lines: Vec<String> = reader
+        .lines()
+        .map(|x| x.unwrap().trim().to_string())
+        .filter(|x| !x.is_empty())
+        .collect();
+    assert_eq!(solve(&lines, row).to_string(), solution);
+}
+
+#[test]
+#[ignore]
+fn test_example() {
+    test_file("example.txt", 10, "56000011");
+}
+
+#[test]
+#[ignore]
+fn test_input() {
+    test_file("input.txt", 2000000, "10908230916597");
+}
@@ -1,44 +1,55 @@
-use pathfinding::prelude::dijkstra;
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
-    io::{self, BufRead}, cmp::max,
+    cmp::max,
 };
 
-use regex::Regex;
+use common::{
+    parse::{assign, comma_list, int, parse_lines},
+    counters,
+    search::{dijkstra, Stats},
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::alpha1,
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
 
 #[derive(Debug)]
-struct Valve {
+pub struct Valve {
     // name: String,
-    rate: u32,
-    neighbors: Vec<String>,
+    pub rate: u32,
+    pub neighbors: Vec<String>,
 }
 
-type Graph = HashMap<String, Valve>;
-type DistanceMap = HashMap<(String, String), Option<usize>>;
+pub type Graph = HashMap<String, Valve>;
+pub type DistanceMap = HashMap<(String, String), Option<usize>>;
+
+pub fn valve(input: &str) -> IResult<&str, (&str, Valve)> {
+    let tunnels = alt((
+        tag("; tunnels lead to valves "),
+        tag("; tunnel leads to valve "),
+    ));
+    map(
+        tuple((
+            preceded(tag("Valve "), alpha1),
+            preceded(tag(" has "), assign("flow rate", int)),
+            preceded(tunnels, comma_list(alpha1)),
+        )),
+        |(name, rate, neighbors)| {
+            let neighbors = neighbors.into_iter().map(String::from).collect();
+            (name, Valve { rate, neighbors })
+        },
+    )(input)
+}
 
-fn parse_input(lines: &[String]) -> Graph {
+pub fn parse_input(lines: &[String]) -> Graph {
     let mut valves = HashMap::new();
-    let re = Regex::new(r"Valve (?P<valve>\w+) has flow rate=(?P<rate>\d+); tunnels? leads? to valves? (?P<neighbors>[A-Z, ]+)")
-        .unwrap();
-    for line in lines {
-        let caps = re.captures(line).unwrap();
-        let name = caps.name("valve").unwrap().as_str().to_owned();
-        let rate: u32 = caps.name("rate").unwrap().as_str().parse().unwrap();
-        let neighbors: Vec<String> = caps
-            .name("neighbors")
-            .unwrap()
-            .as_str()
-            .split(", ")
-            .map(String::from)
-            .collect();
-        valves.insert(
-            name.clone(),
-            Valve {
-                rate,
-                neighbors,
-            },
-        );
+    for (name, valve) in parse_lines(valve, lines).unwrap() {
+        valves.insert(name.to_string(), valve);
     }
     // println!("valves: {:?}", valves);
     valves
@@ -71,7 +82,7 @@ fn parse_input(lines: &[String]) -> Graph {
 //     // }
 // }
 
-fn compute_pressure_release(path: &Vec<String>, graph: &Graph, distance_map: &DistanceMap) -> u32 {
+pub fn compute_pressure_release(path: &[String], graph: &Graph, distance_map: &DistanceMap) -> u32 {
     let mut release = 0u32;
     let mut rate = 0;
     let mut time = 0;
@@ -99,7 +110,7 @@ fn compute_pressure_release(path: &Vec<String>, graph: &Graph, distance_map: &Di
     release
 }
 
-fn walk(
+pub fn walk(
     path: &mut Vec<String>,
     time: u32,
     vector: &mut [String],
@@ -107,6 +118,7 @@ fn walk(
     distance_map: &DistanceMap,
     result: &mut u32,
 ) {
+    counters::incr("walk calls");
     let r = compute_pressure_release(path, graph, distance_map);
     *result = max(*result, r);
     if path.len() == vector.len() {
@@ -144,7 +156,7 @@ fn walk(
     }
 }
 
-fn successors(valve: &str, graph: &Graph) -> Vec<(String, usize)> {
+pub fn successors(valve: &str, graph: &Graph) -> Vec<(String, usize)> {
     if let Some(v) = graph.get(valve) {
         return v
             .neighbors
@@ -155,7 +167,7 @@ fn successors(valve: &str, graph: &Graph) -> Vec<(String, usize)> {
     vec![]
 }
 
-fn distance_map(
+pub fn distance_map(
     graph: &Graph,
     skip: &HashSet<&String>,
 ) -> HashMap<(String, String), Option<usize>> {
@@ -169,15 +181,21 @@ fn distance_map(
             if skip.contains(b) {
                 continue;
             };
-            let distance =
-                dijkstra(a, |valve| successors(valve, graph), |valve| valve == b).map(|r| r.1);
+            let distance = dijkstra(
+                a.clone(),
+                |valve| successors(valve, graph),
+                |valve| valve == b,
+                &mut HashSet::new(),
+                &mut Stats::default(),
+            )
+            .map(|r| r.1);
             result.insert((a.clone(), b.clone()), distance);
         }
     }
     result
 }
 
-fn solution(graph: Graph) -> u32 {
+pub fn solution(graph: Graph) -> u32 {
     let mut skip = HashSet::new();
     let mut good_valves = vec![];
     for valve in graph.keys() {
@@ -200,40 +218,6 @@ fn solution(graph: Graph) -> u32 {
     result
 }
 
-fn solve(lines: &[String]) -> u32 {
+pub fn solve(lines: &[String]) -> u32 {
     solution(parse_input(lines))
 }
-
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "1651");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "1741");
-    }
-}
@@ -1,28 +1,31 @@
 use std::{
     cmp::{max, min},
-    collections::{HashSet, VecDeque},
+    collections::HashSet,
     fmt::Debug,
-    io::{self, BufRead},
     ops::Add,
 };
 
+use common::{
+    counters,
+    search::{bfs, Stats},
+};
 use itertools::Itertools;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Point3D {
-    x: i32,
-    y: i32,
-    z: i32,
+pub struct Point3D {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
 }
 
 impl Copy for Point3D {}
 
 impl Point3D {
-    fn new(x: i32, y: i32, z: i32) -> Self {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
         Self { x, y, z }
     }
 
-    fn between(&self, a: &Point3D, b: &Point3D) -> bool {
+    pub fn between(&self, a: &Point3D, b: &Point3D) -> bool {
         if self.x < min(a.x, b.x) {
             return false;
         }
@@ -53,7 +56,7 @@ impl Add for Point3D {
     }
 }
 
-fn parse_input(lines: &[String]) -> Vec<Point3D> {
+pub fn parse_input(lines: &[String]) -> Vec<Point3D> {
     let mut r = Vec::new();
     for line in lines {
         let Some((x, y, z)) = line.split(',').map(|s| s.parse::<i32>().unwrap()).tuples().next() else { continue; };
@@ -62,7 +65,7 @@ fn parse_input(lines: &[String]) -> Vec<Point3D> {
     r
 }
 
-fn solution(points: &[Point3D]) -> u32 {
+pub fn solution(points: &[Point3D]) -> u32 {
     let mut point_set: HashSet<Point3D> = HashSet::new();
     for p in points.iter() {
         point_set.insert(*p);
@@ -80,8 +83,6 @@ fn solution(points: &[Point3D]) -> u32 {
         pmax.z = std::cmp::max(pmax.z, p.z + 1);
     }
 
-    let mut q: VecDeque<Point3D> = VecDeque::new();
-
     let directions = [
         (-1, 0, 0),
         (0, -1, 0),
@@ -90,70 +91,38 @@ fn solution(points: &[Point3D]) -> u32 {
         (0, 1, 0),
         (0, 0, 1),
     ];
-    
-    // now BFS from one corner
+    let neighbors = |p: &Point3D| {
+        directions
+            .iter()
+            .map(|&(dx, dy, dz)| *p + Point3D::new(dx, dy, dz))
+            .filter(|n| n.between(&pmin, &pmax))
+            .collect::<Vec<Point3D>>()
+    };
+
+    // now flood fill the air from one corner
+    let mut outside = HashSet::new();
+    bfs(
+        pmin,
+        |p| neighbors(p).into_iter().filter(|n| !point_set.contains(n)),
+        |_| false,
+        &mut outside,
+        &mut Stats::default(),
+    );
+    counters::add("flood-fill cells", outside.len() as u64);
+
+    // every block face the air runs into is on the outside, so count it
     let mut count = 0;
-    q.push_back(pmin);
-    let mut visited = HashSet::new();
-    visited.insert(pmin);
-    while !q.is_empty() {
-        let p = q.pop_front().unwrap();
-        for (dx, dy, dz) in directions {
-            let d = Point3D::new(dx, dy, dz);
-            if !(p + d).between(&pmin, &pmax) {
-                continue;
-            }
-            if point_set.contains(&(p + d)) {
-                // every block we run into is from one side, so count it
-                count += 1;
-                continue;
-            }
-            if visited.contains(&(p + d)) {
-                continue;
-            }
-            visited.insert(p + d);
-            q.push_back(p + d);
-        }
+    for p in outside.iter() {
+        count += neighbors(p)
+            .iter()
+            .filter(|n| point_set.contains(n))
+            .count() as u32;
     }
 
     count
 }
 
-fn solve(lines: &[String]) -> u32 {
+pub fn solve(lines: &[String]) -> u32 {
     // let c: usize = env::args().collect::<Vec<String>>()[1].parse().unwrap();
     solution(&parse_input(lines))
 }
-
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "58");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "2044");
-    }
-}
@@ -1,78 +1,75 @@
-use std::{
-    fmt::Debug,
-    io::{self, BufRead},
-};
+use std::fmt::Debug;
 
-use regex::Regex;
+use common::{
+    counters,
+    parse::{assign, int, parse_line, parse_lines},
+    search::{branch_and_bound, Stats},
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{map, opt},
+    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    IResult,
+};
 
 #[derive(Debug)]
-struct OreRobot {
-    ore: u32,
+pub struct OreRobot {
+    pub ore: u32,
 }
 
 #[derive(Debug)]
-struct ClayRobot {
-    ore: u32,
+pub struct ClayRobot {
+    pub ore: u32,
 }
 
 #[derive(Debug)]
-struct ObsidianRobot {
-    ore: u32,
-    clay: u32,
+pub struct ObsidianRobot {
+    pub ore: u32,
+    pub clay: u32,
 }
 
 #[derive(Debug)]
-struct GeodeRobot {
-    ore: u32,
-    obsidian: u32,
+pub struct GeodeRobot {
+    pub ore: u32,
+    pub obsidian: u32,
 }
 
 #[derive(Debug)]
-struct Blueprint {
-    or: OreRobot,
-    cr: ClayRobot,
-    br: ObsidianRobot,
-    gr: GeodeRobot,
+pub struct Blueprint {
+    pub or: OreRobot,
+    pub cr: ClayRobot,
+    pub br: ObsidianRobot,
+    pub gr: GeodeRobot,
 }
 
-impl Blueprint {
-    fn from(line: &str) -> Self {
-        let re = if line.starts_with("Blueprint") {
-            Regex::new(concat!(
-                r"Blueprint (?P<number>\d+): ",
-                r"Each ore robot costs (?P<oro>\d+) ore. ",
-                r"Each clay robot costs (?P<cro>\d+) ore. ",
-                r"Each obsidian robot costs (?P<bro>\d+) ore and (?P<brc>\d+) clay. ",
-                r"Each geode robot costs (?P<gro>\d+) ore and (?P<grb>\d+) obsidian."
-            ))
-            .unwrap()
-        } else {
-            Regex::new(concat!(
-                r"or=(?P<oro>\d+)o ",
-                r"cr=(?P<cro>\d+)o ",
-                r"br=(?P<bro>\d+)o,(?P<brc>\d+)c ",
-                r"gr=(?P<gro>\d+)o,(?P<grb>\d+)b"
-            ))
-            .unwrap()
-        };
+// the costs, ore first
+type Costs = (u32, u32, (u32, u32), (u32, u32));
 
-        let caps = re.captures(line).unwrap();
-        let read = |name: &str| -> u32 { caps.name(name).unwrap().as_str().parse().unwrap() };
+impl Blueprint {
+    fn new((or, cr, br, gr): Costs) -> Self {
         Self {
-            or: OreRobot { ore: read("oro") },
-            cr: ClayRobot { ore: read("cro") },
+            or: OreRobot { ore: or },
+            cr: ClayRobot { ore: cr },
             br: ObsidianRobot {
-                ore: read("bro"),
-                clay: read("brc"),
+                ore: br.0,
+                clay: br.1,
             },
             gr: GeodeRobot {
-                ore: read("gro"),
-                obsidian: read("grb"),
+                ore: gr.0,
+                obsidian: gr.1,
             },
         }
     }
 
-    fn time_to_build_geode_robot(&self, state: &State) -> Option<u32> {
+    /// A blueprint as the puzzle writes it, or in the short form the tests
+    /// use: `or=4o cr=2o br=3o,14c gr=2o,7b`.
+    pub fn from(line: &str) -> Self {
+        parse_line(blueprint, 0, line).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn time_to_build_geode_robot(&self, state: &State) -> Option<u32> {
         if state.ore_robots == 0 {
             return None;
         }
@@ -86,7 +83,7 @@ impl Blueprint {
         Some(std::cmp::max(ore_wait, obsidian_wait))
     }
 
-    fn time_to_build_obsidian_robot(&self, state: &State) -> Option<u32> {
+    pub fn time_to_build_obsidian_robot(&self, state: &State) -> Option<u32> {
         if state.ore_robots == 0 {
             return None;
         }
@@ -100,7 +97,7 @@ impl Blueprint {
         Some(std::cmp::max(ore_wait, clay_wait))
     }
 
-    fn time_to_build_clay_robot(&self, state: &State) -> Option<u32> {
+    pub fn time_to_build_clay_robot(&self, state: &State) -> Option<u32> {
         if state.ore_robots == 0 {
             return None;
         }
@@ -108,7 +105,7 @@ impl Blueprint {
         Some(num::Integer::div_ceil(&needed_ore, &state.ore_robots))
     }
 
-    fn time_to_build_ore_robot(&self, state: &State) -> Option<u32> {
+    pub fn time_to_build_ore_robot(&self, state: &State) -> Option<u32> {
         if state.ore_robots == 0 {
             return None;
         }
@@ -116,7 +113,7 @@ impl Blueprint {
         Some(num::Integer::div_ceil(&needed_ore, &state.ore_robots))
     }
 
-    fn jump_to_geode_robot(&self, s: &State) -> Option<State> {
+    pub fn jump_to_geode_robot(&self, s: &State) -> Option<State> {
         self.time_to_build_geode_robot(s).map(|time| {
             let time = time + 1;
             State {
@@ -133,7 +130,7 @@ impl Blueprint {
         })
     }
 
-    fn jump_to_obsidian_robot(&self, s: &State) -> Option<State> {
+    pub fn jump_to_obsidian_robot(&self, s: &State) -> Option<State> {
         self.time_to_build_obsidian_robot(s).map(|time| {
             let time = time + 1;
             State {
@@ -152,7 +149,7 @@ impl Blueprint {
         })
     }
 
-    fn jump_to_clay_robot(&self, s: &State) -> Option<State> {
+    pub fn jump_to_clay_robot(&self, s: &State) -> Option<State> {
         self.time_to_build_clay_robot(s).map(|time| {
             let time = time + 1;
             State {
@@ -171,7 +168,7 @@ impl Blueprint {
         })
     }
 
-    fn jump_to_ore_robot(&self, s: &State) -> Option<State> {
+    pub fn jump_to_ore_robot(&self, s: &State) -> Option<State> {
         self.time_to_build_ore_robot(s).map(|time| {
             let time = time + 1;
             State {
@@ -190,31 +187,31 @@ impl Blueprint {
         })
     }
 
-    fn max_ore(&self) -> u32 {
+    pub fn max_ore(&self) -> u32 {
         vec![self.or.ore, self.cr.ore, self.br.ore, self.gr.ore]
             .into_iter()
             .max()
             .unwrap()
     }
-    fn max_clay(&self) -> u32 {
+    pub fn max_clay(&self) -> u32 {
         self.br.clay
     }
-    fn max_obsidian(&self) -> u32 {
+    pub fn max_obsidian(&self) -> u32 {
         self.gr.obsidian
     }
 }
 
-struct Solver<'a> {
-    time_limit: u32,
-    blueprint: &'a Blueprint,
+pub struct Solver<'a> {
+    pub time_limit: u32,
+    pub blueprint: &'a Blueprint,
     // path: Vec<State>,
-    max_geodes_found: u32,
-    max_ore_needed: u32,
-    max_clay_needed: u32,
-    max_obsidian_needed: u32,
+    pub max_geodes_found: u32,
+    pub max_ore_needed: u32,
+    pub max_clay_needed: u32,
+    pub max_obsidian_needed: u32,
 }
 
-fn sum_between(a: u32, b: u32) -> u32 {
+pub fn sum_between(a: u32, b: u32) -> u32 {
     if b < a {
         return 0;
     }
@@ -227,7 +224,7 @@ fn sum_between(a: u32, b: u32) -> u32 {
 }
 
 impl<'a> Solver<'a> {
-    fn new(blueprint: &'a Blueprint, time_limit: u32) -> Self {
+    pub fn new(blueprint: &'a Blueprint, time_limit: u32) -> Self {
         // let path = Vec::new();
         Self {
             time_limit,
@@ -240,155 +237,159 @@ impl<'a> Solver<'a> {
         }
     }
 
-    fn solve(&mut self) -> u32 {
-        self._solve(State::from("o=0 c=0 b=0 g=0 or=1 cr=0 br=0 gr=0 t=0"));
-        self.max_geodes_found
-    }
-
-    fn _solve(&mut self, s: State) {
-        if s.time > self.time_limit {
-            return;
-        }
-
-        // assuming I build one geode robot each remaining minute
-        let rem = self.time_limit - s.time;
-        if rem > 0 {
-            let potential_geodes = s.geodes + sum_between(s.geode_robots, s.geode_robots + rem - 1);
-            if potential_geodes <= self.max_geodes_found {
-                // this branch won't beat the record
-                return;
-            }
-        }
-
-        self.max_geodes_found = std::cmp::max(s.geodes, self.max_geodes_found);
-
-        if let Some(state) = self.blueprint.jump_to_geode_robot(&s) {
-            self._solve(state);
-            // self.path.pop();
+    pub fn successors(&self, s: &State) -> Vec<State> {
+        let mut result = vec![];
+        if let Some(state) = self.blueprint.jump_to_geode_robot(s) {
+            result.push(state);
         }
         if s.obsidian_robots < self.max_obsidian_needed {
-            if let Some(state) = self.blueprint.jump_to_obsidian_robot(&s) {
-                // println!("{:?}", s);
-                self._solve(state);
+            if let Some(state) = self.blueprint.jump_to_obsidian_robot(s) {
+                result.push(state);
             }
-            // self.path.pop();
         }
         if s.clay_robots < self.max_clay_needed {
-            if let Some(state) = self.blueprint.jump_to_clay_robot(&s) {
-                self._solve(state);
+            if let Some(state) = self.blueprint.jump_to_clay_robot(s) {
+                result.push(state);
             }
         }
         if s.ore_robots < self.max_ore_needed {
-            if let Some(state) = self.blueprint.jump_to_ore_robot(&s) {
-                self._solve(state);
+            if let Some(state) = self.blueprint.jump_to_ore_robot(s) {
+                result.push(state);
             }
         }
+        result.retain(|state| state.time <= self.time_limit);
+        result
+    }
+
+    pub fn potential_geodes(&self, s: &State) -> u32 {
+        // assuming I build one geode robot each remaining minute
+        let rem = self.time_limit - s.time;
+        if rem == 0 {
+            return s.geodes;
+        }
+        s.geodes + sum_between(s.geode_robots, s.geode_robots + rem - 1)
     }
+
+    pub fn solve(&mut self) -> u32 {
+        let mut stats = Stats::default();
+        let (_, geodes) = branch_and_bound(
+            State::from("o=0 c=0 b=0 g=0 or=1 cr=0 br=0 gr=0 t=0"),
+            |s| self.successors(s),
+            |s| s.geodes,
+            |s| self.potential_geodes(s),
+            &mut stats,
+        );
+        counters::add("states visited", stats.expanded as u64);
+        counters::add("states pruned", stats.pruned as u64);
+        self.max_geodes_found = geodes;
+        self.max_geodes_found
+    }
+}
+
+// `Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. ...`
+fn long_form(input: &str) -> IResult<&str, Costs> {
+    let robot = |name, cost| delimited(tag(name), cost, char('.'));
+    let pair = |other| separated_pair(int, tag(" ore and "), terminated(int, other));
+    preceded(
+        tuple((tag("Blueprint "), int::<u32>, tag(":"))),
+        tuple((
+            robot(" Each ore robot costs ", terminated(int, tag(" ore"))),
+            robot(" Each clay robot costs ", terminated(int, tag(" ore"))),
+            preceded(
+                tag(" Each obsidian robot costs "),
+                terminated(pair(tag(" clay")), char('.')),
+            ),
+            preceded(
+                tag(" Each geode robot costs "),
+                terminated(pair(tag(" obsidian")), char('.')),
+            ),
+        )),
+    )(input)
+}
+
+// `or=4o cr=2o br=3o,14c gr=2o,7b`
+fn short_form(input: &str) -> IResult<&str, Costs> {
+    let cost = |unit| terminated(int, char(unit));
+    let pair = |other| separated_pair(cost('o'), char(','), cost(other));
+    tuple((
+        terminated(assign("or", cost('o')), char(' ')),
+        terminated(assign("cr", cost('o')), char(' ')),
+        terminated(assign("br", pair('c')), char(' ')),
+        assign("gr", pair('b')),
+    ))(input)
+}
+
+pub fn blueprint(input: &str) -> IResult<&str, Blueprint> {
+    map(alt((long_form, short_form)), Blueprint::new)(input)
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct State {
-    ore: u32,
-    clay: u32,
-    obsidian: u32,
-    geodes: u32,
-    ore_robots: u32,
-    clay_robots: u32,
-    obsidian_robots: u32,
-    geode_robots: u32,
-    time: u32,
+pub struct State {
+    pub ore: u32,
+    pub clay: u32,
+    pub obsidian: u32,
+    pub geodes: u32,
+    pub ore_robots: u32,
+    pub clay_robots: u32,
+    pub obsidian_robots: u32,
+    pub geode_robots: u32,
+    pub time: u32,
 }
 
 impl State {
-    fn from(line: &str) -> Self {
+    pub fn from(line: &str) -> Self {
         // o=0 c=0 b=0 g=0 or=1 cr=0 br=0 gr=0 t=0
-        let re = Regex::new(concat!(
-            r"o=(?P<o>\d+) ",
-            r"c=(?P<c>\d+) ",
-            r"b=(?P<b>\d+) ",
-            r"g=(?P<g>\d+) ",
-            r"or=(?P<or>\d+) ",
-            r"cr=(?P<cr>\d+) ",
-            r"br=(?P<br>\d+) ",
-            r"gr=(?P<gr>\d+) ",
-            r"t=(?P<t>\d+)",
-        ))
-        .unwrap();
-
-        let caps = re.captures(line).unwrap();
-        let read = |name: &str| -> u32 { caps.name(name).unwrap().as_str().parse().unwrap() };
+        let read = |name| terminated(assign(name, int), opt(char(' ')));
+        let fields = tuple((
+            read("o"),
+            read("c"),
+            read("b"),
+            read("g"),
+            read("or"),
+            read("cr"),
+            read("br"),
+            read("gr"),
+            read("t"),
+        ));
+        let (o, c, b, g, or, cr, br, gr, t) =
+            parse_line(fields, 0, line).unwrap_or_else(|e| panic!("{}", e));
         Self {
-            ore: read("o"),
-            clay: read("c"),
-            obsidian: read("b"),
-            geodes: read("g"),
-            ore_robots: read("or"),
-            clay_robots: read("cr"),
-            obsidian_robots: read("br"),
-            geode_robots: read("gr"),
-            time: read("t"),
+            ore: o,
+            clay: c,
+            obsidian: b,
+            geodes: g,
+            ore_robots: or,
+            clay_robots: cr,
+            obsidian_robots: br,
+            geode_robots: gr,
+            time: t,
         }
     }
 }
 
-fn parse_input(lines: &[String]) -> Vec<Blueprint> {
-    let mut r = Vec::new();
-
-    for line in lines {
-        r.push(Blueprint::from(line));
-    }
-    r
+pub fn parse_input(lines: &[String]) -> Vec<Blueprint> {
+    parse_lines(blueprint, lines).unwrap_or_else(|e| panic!("{}", e))
 }
 
-fn solution(blueprints: &[Blueprint]) -> u32 {
+pub fn solution(blueprints: &[Blueprint]) -> u32 {
     let mut score = 0;
     for (i, blueprint) in blueprints.iter().enumerate() {
         let mut solver = Solver::new(blueprint, 24);
         let g = solver.solve();
         // println!("{:?}", blueprint);
         score += (i as u32 + 1) * g;
-        println!("Blueprint {}: {} geodes, score = {}", i + 1, g, score);
     }
     score
 }
 
-fn solve(lines: &[String]) -> u32 {
+pub fn solve(lines: &[String]) -> u32 {
     solution(&parse_input(lines))
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::BufReader};
-
     use super::*;
 
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "33");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "978");
-    }
-
     #[test]
     fn test_ore_robot_jump() {
         let bp = Blueprint::from("or=4o cr=2o br=3o,14c gr=2o,7b");
@@ -1,6 +1,4 @@
-use std::io::{self, BufRead};
-
-fn parse_input(lines: &[String]) -> List {
+pub fn parse_input(lines: &[String]) -> List {
     let mut r = List::new();
 
     for (i, line) in lines.iter().enumerate() {
@@ -9,12 +7,12 @@ fn parse_input(lines: &[String]) -> List {
     r
 }
 
-type List = Vec<(i64, usize)>;
+pub type List = Vec<(i64, usize)>;
 
-trait CircularList {
+pub trait CircularList {
     fn move_item(&mut self, from: usize, to: usize);
     fn move_circular(&mut self, pos: usize, count: i64);
-    fn print_from_zero(&self);
+    fn render_from_zero(&self) -> String;
 }
 
 impl CircularList for List {
@@ -42,20 +40,17 @@ impl CircularList for List {
         self.move_item(pos, to);
     }
 
-    fn print_from_zero(&self) {
+    // the numbers starting from 0, as in the puzzle's examples
+    fn render_from_zero(&self) -> String {
         let zp = self.iter().position(|x| x.0 == 0).unwrap();
-        for i in 0..self.len() {
-            print!(
-                "{}{}",
-                self[(zp + i).rem_euclid(self.len())].0,
-                if i < (self.len() - 1) { ", " } else { "" }
-            );
-        }
-        println!();
+        (0..self.len())
+            .map(|i| self[(zp + i).rem_euclid(self.len())].0.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
     }
 }
 
-fn solution(nums: &mut List) -> i64 {
+pub fn solution(nums: &mut List) -> i64 {
     let mut nums: List = nums.iter().map(|(val, i)| (*val * 811589153, *i)).collect();
     let orig = nums.clone();
     for _ in 0..10 {
@@ -71,43 +66,14 @@ fn solution(nums: &mut List) -> i64 {
     a.0 + b.0 + c.0
 }
 
-fn solve(lines: &[String]) -> i64 {
+pub fn solve(lines: &[String]) -> i64 {
     solution(&mut parse_input(lines))
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::BufReader};
-
     use super::*;
 
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "1623178306");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "8798438007673");
-    }
-
     fn make_list(vec: Vec<i64>) -> List {
         vec.into_iter().map(|val| (val, val as usize)).collect()
     }
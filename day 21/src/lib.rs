@@ -0,0 +1,2 @@
+pub mod part1;
+pub mod part2;
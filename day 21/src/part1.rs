@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use common::parse::{int, parse_lines};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, one_of},
+    combinator::{map, recognize},
+    sequence::{delimited, separated_pair, tuple},
+    IResult,
+};
+
+#[derive(Debug)]
+pub enum Phrase {
+    Expr(String, String, String),
+    Number(i64),
+}
+
+pub type Conversation = HashMap<String, Phrase>;
+
+pub fn phrase(input: &str) -> IResult<&str, (&str, Phrase)> {
+    let op = delimited(char(' '), recognize(one_of("+-*/")), char(' '));
+    let expr = map(
+        tuple((alpha1, op, alpha1)),
+        |(lhs, op, rhs): (&str, &str, &str)| {
+            Phrase::Expr(lhs.to_string(), op.to_string(), rhs.to_string())
+        },
+    );
+    separated_pair(alpha1, tag(": "), alt((map(int, Phrase::Number), expr)))(input)
+}
+
+pub fn parse_input(lines: &[String]) -> HashMap<String, Phrase> {
+    let mut map = HashMap::new();
+    for (name, phrase) in parse_lines(phrase, lines).unwrap_or_else(|e| panic!("{}", e)) {
+        map.insert(name.to_string(), phrase);
+    }
+    map
+}
+
+pub fn evaluate(lv: i64, op: &str, rv: i64) -> i64 {
+    match op {
+        "+" => lv + rv,
+        "-" => lv - rv,
+        "*" => lv * rv,
+        "/" => lv / rv,
+        _ => panic!("invalid operator: {}", op),
+    }
+}
+
+pub fn solution(conversation: &Conversation) -> i64 {
+    let mut known: HashMap<&String, i64> = HashMap::new();
+    let names: Vec<String> = conversation.keys().map(|x| (*x).clone()).collect();
+    loop {
+        let previously_known = known.len();
+        for name in names.iter() {
+            if !known.contains_key(name) {
+                let value = conversation.get(name).unwrap();
+
+                match value {
+                    Phrase::Number(v) => {
+                        known.insert(name, *v);
+                    }
+                    Phrase::Expr(lhs, op, rhs) => {
+                        if let Some(lv) = known.get(lhs) {
+                            if let Some(rv) = known.get(rhs) {
+                                let r = evaluate(*lv, op, *rv);
+                                known.insert(name, r);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if known.contains_key(&"root".to_string()) {
+            break;
+        }
+        if known.len() == previously_known {
+            break;
+        }
+    }
+    let r = known.get(&"root".to_string()).unwrap();
+    *r
+}
+
+pub fn solve(lines: &[String]) -> i64 {
+    solution(&parse_input(lines))
+}
@@ -1,13 +1,20 @@
 use std::{
     collections::HashMap,
     fmt::Display,
-    io::{self, BufRead},
 };
 
-use regex::Regex;
+use common::parse::{int, parse_lines};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, one_of},
+    combinator::{map, recognize},
+    sequence::{delimited, separated_pair, tuple},
+    IResult,
+};
 
 #[derive(Debug)]
-enum Op {
+pub enum Op {
     Add,
     Sub,
     Mul,
@@ -15,7 +22,7 @@ enum Op {
 }
 
 impl Op {
-    fn from(s: &str) -> Self {
+    pub fn from(s: &str) -> Self {
         match s {
             "+" => Op::Add,
             "-" => Op::Sub,
@@ -39,36 +46,33 @@ impl Display for Op {
 }
 
 #[derive(Debug)]
-enum Phrase {
+pub enum Phrase {
     Expr(String, Op, String),
     Number(i64),
 }
 
-type Conversation = HashMap<String, Phrase>;
+pub type Conversation = HashMap<String, Phrase>;
+
+pub fn phrase(input: &str) -> IResult<&str, (&str, Phrase)> {
+    let op = delimited(char(' '), recognize(one_of("+-*/")), char(' '));
+    let expr = map(
+        tuple((alpha1, op, alpha1)),
+        |(lhs, op, rhs): (&str, &str, &str)| {
+            Phrase::Expr(lhs.to_string(), Op::from(op), rhs.to_string())
+        },
+    );
+    separated_pair(alpha1, tag(": "), alt((map(int, Phrase::Number), expr)))(input)
+}
 
-fn parse_input(lines: &[String]) -> HashMap<String, Phrase> {
+pub fn parse_input(lines: &[String]) -> HashMap<String, Phrase> {
     let mut map = HashMap::new();
-    let r1 = Regex::new(r"(\w+): (\d+)$").unwrap();
-    let r2 = Regex::new(r"(\w+): (\w+) ([+*/-]) (\w+)").unwrap();
-
-    for line in lines {
-        if let Some(caps) = r1.captures(line) {
-            let name: String = caps.get(1).unwrap().as_str().to_string();
-            let value: i64 = caps.get(2).unwrap().as_str().parse().unwrap();
-            map.insert(name, Phrase::Number(value));
-        }
-        if let Some(caps) = r2.captures(line) {
-            let name: String = caps.get(1).unwrap().as_str().to_string();
-            let lhs: String = caps.get(2).unwrap().as_str().to_string();
-            let op: String = caps.get(3).unwrap().as_str().to_string();
-            let rhs: String = caps.get(4).unwrap().as_str().to_string();
-            map.insert(name, Phrase::Expr(lhs, Op::from(&op), rhs));
-        }
+    for (name, phrase) in parse_lines(phrase, lines).unwrap_or_else(|e| panic!("{}", e)) {
+        map.insert(name.to_string(), phrase);
     }
     map
 }
 
-fn evaluate(lv: i64, op: &Op, rv: i64) -> i64 {
+pub fn evaluate(lv: i64, op: &Op, rv: i64) -> i64 {
     match op {
         Op::Add => lv + rv,
         Op::Sub => lv - rv,
@@ -77,7 +81,7 @@ fn evaluate(lv: i64, op: &Op, rv: i64) -> i64 {
     }
 }
 
-fn walk(
+pub fn walk(
     conversation: &Conversation,
     known: &HashMap<&String, i64>,
     name: &str,
@@ -116,7 +120,7 @@ fn walk(
     }
 }
 
-fn solution(conversation: &Conversation) -> i64 {
+pub fn solution(conversation: &Conversation) -> i64 {
     let mut known: HashMap<&String, i64> = HashMap::new();
 
     let names: Vec<String> = conversation.keys().map(|x| (*x).clone()).collect();
@@ -126,7 +130,7 @@ fn solution(conversation: &Conversation) -> i64 {
             if name == "humn" {
                 continue;
             }
-            if known.get(name).is_none() {
+            if !known.contains_key(name) {
                 let value = conversation.get(name).unwrap();
 
                 match value {
@@ -166,40 +170,6 @@ fn solution(conversation: &Conversation) -> i64 {
     0
 }
 
-fn solve(lines: &[String]) -> i64 {
+pub fn solve(lines: &[String]) -> i64 {
     solution(&parse_input(lines))
 }
-
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap().trim().to_string())
-            .filter(|x| !x.is_empty())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "301");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "3882224466191");
-    }
-}
@@ -1,13 +1,12 @@
 use std::{
     collections::HashMap,
     fmt::Display,
-    io::{self, BufRead},
 };
 
 use regex::Regex;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Cell {
+pub enum Cell {
     Blank,
     Dot,
     Wall,
@@ -30,24 +29,24 @@ impl Display for Cell {
 }
 
 #[derive(Debug)]
-enum Move {
+pub enum Move {
     Right,
     Left,
     Walk(usize),
 }
 
-struct Input {
-    map: Vec<Vec<Cell>>,
-    steps: Vec<Move>,
+pub struct Input {
+    pub map: Vec<Vec<Cell>>,
+    pub steps: Vec<Move>,
 }
 
-fn parse_input(lines: &[String]) -> Input {
+pub fn parse_input(lines: &[String]) -> Input {
     let mut steps = Vec::new();
     let mut map: Vec<Vec<Cell>> = Vec::new();
     let mut max_length = 0;
+    let re = Regex::new(r"\d+|L|R").unwrap();
     for line in lines {
         if line.contains('L') {
-            let re = Regex::new(r"\d+|L|R").unwrap();
             for m in re.find_iter(line) {
                 steps.push(match m.as_str() {
                     "L" => Move::Left,
@@ -78,9 +77,9 @@ fn parse_input(lines: &[String]) -> Input {
     Input { map, steps }
 }
 
-struct Vector {
-    x: i32,
-    y: i32,
+pub struct Vector {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl From<(i32, i32)> for Vector {
@@ -92,7 +91,7 @@ impl From<(i32, i32)> for Vector {
     }
 }
 
-fn solution(input: &mut Input) -> usize {
+pub fn solution(input: &mut Input) -> usize {
     let mut x = input.map[0].iter().position(|c| *c == Cell::Dot).unwrap();
     let mut y = 0usize;
     let directions = HashMap::from([
@@ -170,21 +169,6 @@ fn solution(input: &mut Input) -> usize {
 
         input.map[y][x] = Cell::Dir(dir);
     }
-    for line in input.map.iter() {
-        for c in line.iter() {
-            print!(
-                "{}",
-                match c {
-                    Cell::Blank => ' ',
-                    Cell::Dot => '.',
-                    Cell::Wall => '#',
-                    Cell::Dir(c) => *c,
-                }
-            );
-        }
-        println!()
-    }
-    println!("final: {} {}, facing {}", x, y, dir);
     let d = match dir {
         '>' => 0,
         'v' => 1,
@@ -195,39 +179,6 @@ fn solution(input: &mut Input) -> usize {
     1000 * (y + 1) + 4 * (x + 1) + d
 }
 
-fn solve(lines: &[String]) -> usize {
+pub fn solve(lines: &[String]) -> usize {
     solution(&mut parse_input(lines))
 }
-
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|x| x.unwrap())
-            .collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "6032");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "88268");
-    }
-}
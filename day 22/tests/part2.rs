@@ -0,0 +1,24 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use day22::part2::*;
+
+fn test_file(filename: &str, solution: &str) {
+    let reader = BufReader::new(File::open(filename).unwrap());
+
+    let lines: Vec<String> = reader.lines().map(|x| x.unwrap()).collect();
+    assert_eq!(solve(&lines).to_string(), solution);
+}
+
+#[test]
+#[ignore]
+fn test_example() {
+    test_file("example.txt", "6032");
+}
+
+#[test]
+fn test_input() {
+    test_file("input.txt", "124302");
+}
@@ -1,13 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
-    io::{self, BufRead},
     ops::Add,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: i32,
-    y: i32,
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl Add for Point {
@@ -27,11 +26,11 @@ impl Add for &Point {
 }
 
 impl Point {
-    fn new(x: i32, y: i32) -> Self {
+    pub fn new(x: i32, y: i32) -> Self {
         Self { x, y }
     }
 
-    fn can_move_in_direction(&self, positions: &HashSet<Point>, d: &Point) -> bool {
+    pub fn can_move_in_direction(&self, positions: &HashSet<Point>, d: &Point) -> bool {
         let mut a = *self + Point { x: -1, y: d.y };
         let b = self + d;
         let mut c = *self + Point { x: 1, y: d.y };
@@ -42,7 +41,7 @@ impl Point {
         !(positions.contains(&a) || positions.contains(&b) || positions.contains(&c))
     }
 
-    fn is_alone(&self, positions: &HashSet<Point>) -> bool {
+    pub fn is_alone(&self, positions: &HashSet<Point>) -> bool {
         for dx in [-1, 0, 1] {
             for dy in [-1, 0, 1] {
                 if dx == 0 && dy == 0 {
@@ -57,7 +56,7 @@ impl Point {
     }
 }
 
-fn parse_input(lines: &[String]) -> Vec<Point> {
+pub fn parse_input(lines: &[String]) -> Vec<Point> {
     let mut r = Vec::new();
     for (y, line) in lines.iter().enumerate() {
         for (x, c) in line.chars().enumerate() {
@@ -86,7 +85,7 @@ fn parse_input(lines: &[String]) -> Vec<Point> {
 //     }
 // }
 
-fn solution(points: &[Point]) -> u32 {
+pub fn solution(points: &[Point]) -> u32 {
     let mut positions: HashSet<Point> = points.iter().copied().collect();
     // key is "destination", value is "source"
     let mut new_positions: HashMap<Point, Vec<Point>> = HashMap::new();
@@ -144,36 +143,6 @@ fn solution(points: &[Point]) -> u32 {
     (maxx.abs_diff(minx) + 1) * (maxy.abs_diff(miny) + 1) - positions.len() as u32
 }
 
-fn solve(lines: &[String]) -> u32 {
+pub fn solve(lines: &[String]) -> u32 {
     solution(&parse_input(lines))
 }
-
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader.lines().map(|x| x.unwrap()).collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "110");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "3925");
-    }
-}
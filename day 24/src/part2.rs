@@ -1,13 +1,14 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    io::{self, BufRead},
-    ops::Add,
+use std::{collections::HashSet, ops::Add};
+
+use common::{
+    counters,
+    search::{bfs, DedupBy, Stats},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: i32,
-    y: i32,
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl Add for Point {
@@ -27,24 +28,24 @@ impl Add for &Point {
 }
 
 impl Point {
-    fn new(x: i32, y: i32) -> Self {
+    pub fn new(x: i32, y: i32) -> Self {
         Self { x, y }
     }
 }
 
-struct Input {
-    start: Point,
-    end: Point,
-    width: usize,  // blocks between the walls
-    height: usize, // blocks between the walls
+pub struct Input {
+    pub start: Point,
+    pub end: Point,
+    pub width: usize,  // blocks between the walls
+    pub height: usize, // blocks between the walls
 
-    left_blizzards: HashSet<Point>,
-    right_blizzards: HashSet<Point>,
-    up_blizzards: HashSet<Point>,
-    down_blizzards: HashSet<Point>,
+    pub left_blizzards: HashSet<Point>,
+    pub right_blizzards: HashSet<Point>,
+    pub up_blizzards: HashSet<Point>,
+    pub down_blizzards: HashSet<Point>,
 }
 
-fn parse_input(lines: &[String]) -> Input {
+pub fn parse_input(lines: &[String]) -> Input {
     let lines: Vec<&String> = lines.iter().collect();
     let mut start = Point { x: 0, y: 0 };
     let mut end = Point { x: 0, y: 0 };
@@ -98,19 +99,19 @@ fn parse_input(lines: &[String]) -> Input {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct State {
-    step: u32,
-    pos: Point,
+pub struct State {
+    pub step: u32,
+    pub pos: Point,
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Visit {
-    point: Point,
-    blizzard_state: u32,
+pub struct Visit {
+    pub point: Point,
+    pub blizzard_state: u32,
 }
 
 impl Visit {
-    fn new(point: Point, blizzard_state: u32) -> Self {
+    pub fn new(point: Point, blizzard_state: u32) -> Self {
         Self {
             point,
             blizzard_state,
@@ -118,18 +119,16 @@ impl Visit {
     }
 }
 
-struct Solver<'a> {
-    visited: HashSet<Visit>,
-    input: &'a Input,
-    start: Point,
-    end: Point,
-    cycle: u32,
+pub struct Solver<'a> {
+    pub input: &'a Input,
+    pub start: Point,
+    pub end: Point,
+    pub cycle: u32,
 }
 
 impl<'a> Solver<'a> {
-    fn new(input: &'a Input, start: Point, end: Point) -> Self {
+    pub fn new(input: &'a Input, start: Point, end: Point) -> Self {
         Self {
-            visited: HashSet::new(),
             input,
             start,
             end,
@@ -137,7 +136,7 @@ impl<'a> Solver<'a> {
         }
     }
 
-    fn any_blizzards_at(&self, point: &Point, step: u32) -> bool {
+    pub fn any_blizzards_at(&self, point: &Point, step: u32) -> bool {
         if *point == self.start {
             return false;
         }
@@ -172,17 +171,7 @@ impl<'a> Solver<'a> {
         false
     }
 
-    fn mark_visit(&mut self, point: Point, step: u32) {
-        self.visited
-            .insert(Visit::new(point, step.rem_euclid(self.cycle)));
-    }
-
-    fn has_visited(&self, point: Point, step: u32) -> bool {
-        self.visited
-            .contains(&Visit::new(point, step.rem_euclid(self.cycle)))
-    }
-
-    fn out_of_bounds(&self, point: &Point) -> bool {
+    pub fn out_of_bounds(&self, point: &Point) -> bool {
         if *point == self.start {
             return false;
         }
@@ -198,7 +187,7 @@ impl<'a> Solver<'a> {
         false
     }
 
-    fn solve(&mut self, step: u32) -> u32 {
+    pub fn solve(&self, step: u32) -> u32 {
         let moves = [
             ("d", Point::new(0, 1)),
             ("r", Point::new(1, 0)),
@@ -206,77 +195,63 @@ impl<'a> Solver<'a> {
             ("l", Point::new(-1, 0)),
             ("u", Point::new(0, -1)),
         ];
-        let mut q = VecDeque::new();
-        q.push_back(State { pos: self.start, step });
-        while !q.is_empty() {
-            let s = q.pop_front().unwrap();
-            if s.pos == self.end {
-                return s.step;
-            }
-
+        let successors = |s: &State| {
+            let mut result = vec![];
             for (_, dir) in moves {
                 let np = s.pos + dir;
                 let ns = s.step + 1;
-                if self.has_visited(np, ns) {
-                    continue;
-                }
-                self.mark_visit(np, ns);
-                let ns = s.step + 1;
                 if self.out_of_bounds(&np) {
                     continue;
                 }
                 if self.any_blizzards_at(&np, ns) {
                     continue;
                 }
-                q.push_back(State { pos: np, step: ns });
+                result.push(State { pos: np, step: ns });
             }
+            result
+        };
+        // the blizzards repeat, so a position is only worth visiting once
+        // per cycle
+        let mut stats = Stats::default();
+        let mut visited =
+            DedupBy::new(|s: &State| Visit::new(s.pos, s.step.rem_euclid(self.cycle)));
+        let path = bfs(
+            State {
+                pos: self.start,
+                step,
+            },
+            successors,
+            |s| s.pos == self.end,
+            &mut visited,
+            &mut stats,
+        );
+        counters::add("states expanded", stats.expanded as u64);
+        counters::add("visited hits", stats.duplicates as u64);
+        counters::max("max queue", stats.max_frontier as u64);
+        match path {
+            Some(path) => path.last().unwrap().step,
+            None => step,
         }
-        step
     }
 }
 
-fn solution(input: &Input) -> u32 {
-    let mut solver_a = Solver::new(input, input.start, input.end);
-    let mut solver_b = Solver::new(input, input.end, input.start);
-    let mut solver_c = Solver::new(input, input.start, input.end);
+pub fn solution(input: &Input) -> u32 {
+    let solver_a = Solver::new(input, input.start, input.end);
+    let solver_b = Solver::new(input, input.end, input.start);
+    let solver_c = Solver::new(input, input.start, input.end);
     let a = solver_a.solve(0);
     let b = solver_b.solve(a);
     solver_c.solve(b)
 }
 
-fn solve(lines: &[String]) -> u32 {
+pub fn solve(lines: &[String]) -> u32 {
     solution(&parse_input(lines))
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::BufReader};
-
     use super::*;
 
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader.lines().map(|x| x.unwrap()).collect();
-        assert_eq!(solve(&lines).to_string(), solution);
-    }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "54");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "739");
-    }
-
     #[test]
     fn test_blizzard() {
         let lines = ["#.######", "#<.....#", "######.#"].map(|x| x.to_string());
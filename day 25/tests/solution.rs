@@ -0,0 +1,23 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use day25::*;
+
+fn test_file(filename: &str, solution: &str) {
+    let reader = BufReader::new(File::open(filename).unwrap());
+
+    let lines: Vec<String> = reader.lines().map(|x| x.unwrap()).collect();
+    assert_eq!(solve(&lines), solution);
+}
+
+#[test]
+fn test_example() {
+    test_file("example.txt", "2=-1=0");
+}
+
+#[test]
+fn test_input() {
+    test_file("input.txt", "2=10---0===-1--01-20");
+}
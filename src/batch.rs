@@ -0,0 +1,183 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufRead, BufReader},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use crate::days::{Day, Solver};
+
+// answers registry kept next to the inputs, one `<file> <part> <answer>` per line
+pub const REGISTRY: &str = "answers.txt";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Unknown,
+    Ok,
+    Mismatch(String),
+}
+
+pub struct Row {
+    pub file: String,
+    pub answers: Vec<(String, Check)>,
+}
+
+pub fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    let reader = BufReader::new(File::open(path)?);
+    reader.lines().collect()
+}
+
+// multi-line answers (day 10's CRT) are shown and registered as `row/row/...`
+pub fn one_line(answer: &str) -> String {
+    answer.lines().collect::<Vec<&str>>().join("/")
+}
+
+pub fn parse_registry(lines: &[String]) -> HashMap<(String, usize), String> {
+    let mut registry = HashMap::new();
+    for line in lines {
+        let parts: Vec<&str> = line.splitn(3, char::is_whitespace).collect();
+        if let [file, part, answer] = parts[..] {
+            if let Ok(part) = part.parse() {
+                registry.insert((file.to_string(), part), answer.trim().to_string());
+            }
+        }
+    }
+    registry
+}
+
+fn run_solver(solver: Solver, lines: &[String]) -> String {
+    match panic::catch_unwind(AssertUnwindSafe(|| solver(lines))) {
+        Ok(answer) => one_line(&answer),
+        Err(_) => "panicked".to_string(),
+    }
+}
+
+pub fn batch(day: &Day, dir: &Path) -> io::Result<Vec<Row>> {
+    let registry = match read_lines(&dir.join(REGISTRY)) {
+        Ok(lines) => parse_registry(&lines),
+        Err(_) => HashMap::new(),
+    };
+
+    let mut files: Vec<String> = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if entry.file_type()?.is_file() && name != REGISTRY {
+            files.push(name);
+        }
+    }
+    files.sort();
+
+    let mut rows = vec![];
+    for file in files {
+        let lines = read_lines(&dir.join(&file))?;
+        let mut answers = vec![];
+        for (i, solver) in day.solvers().iter().enumerate() {
+            let answer = run_solver(*solver, &lines);
+            let check = match registry.get(&(file.clone(), i + 1)) {
+                None => Check::Unknown,
+                Some(expected) if *expected == answer => Check::Ok,
+                Some(expected) => Check::Mismatch(expected.clone()),
+            };
+            answers.push((answer, check));
+        }
+        rows.push(Row { file, answers });
+    }
+    Ok(rows)
+}
+
+pub fn render(rows: &[Row]) -> Vec<String> {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            let mut cells = vec![row.file.clone()];
+            for (answer, check) in row.answers.iter() {
+                cells.push(match check {
+                    Check::Unknown => answer.clone(),
+                    Check::Ok => format!("{} (ok)", answer),
+                    Check::Mismatch(expected) => format!("{} (expected {})", answer, expected),
+                });
+            }
+            cells
+        })
+        .collect();
+
+    let columns = rows.iter().map(|r| r.answers.len()).max().unwrap_or(0);
+    let mut header = vec!["file".to_string()];
+    for part in 1..=columns {
+        header.push(format!("part {}", part));
+    }
+
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in cells.iter() {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let mut table = vec![];
+    for row in std::iter::once(&header).chain(cells.iter()) {
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| format!("{:width$}", cell, width = widths[i]))
+            .collect();
+        table.push(line.join("  ").trim_end().to_string());
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_registry() {
+        let lines = [
+            "alice.txt 1 71502",
+            "alice.txt 2 ##..#/#..##",
+            "",
+            "bob.txt x 3",
+        ];
+        let lines: Vec<String> = lines.iter().map(|x| x.to_string()).collect();
+        let registry = parse_registry(&lines);
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry[&("alice.txt".to_string(), 1)], "71502");
+        assert_eq!(registry[&("alice.txt".to_string(), 2)], "##..#/#..##");
+    }
+
+    #[test]
+    fn test_one_line() {
+        assert_eq!(one_line("24000"), "24000");
+        assert_eq!(one_line("##..\n..##"), "##../..##");
+    }
+
+    #[test]
+    fn test_render() {
+        let rows = vec![
+            Row {
+                file: "alice.txt".to_string(),
+                answers: vec![
+                    ("24000".to_string(), Check::Ok),
+                    ("45000".to_string(), Check::Mismatch("45001".to_string())),
+                ],
+            },
+            Row {
+                file: "b.txt".to_string(),
+                answers: vec![
+                    ("1".to_string(), Check::Unknown),
+                    ("2".to_string(), Check::Unknown),
+                ],
+            },
+        ];
+        assert_eq!(
+            render(&rows),
+            vec![
+                "file       part 1      part 2",
+                "alice.txt  24000 (ok)  45000 (expected 45001)",
+                "b.txt      1           2",
+            ]
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::{Duration, Instant};
+
+use common::counters;
+
+use crate::{batch::one_line, days::Solver};
+
+pub struct Bench {
+    pub answer: String,
+    pub times: Vec<Duration>,
+    // counters from the last run, they are the same for every run
+    pub counters: Vec<(&'static str, u64)>,
+}
+
+pub fn bench(solver: Solver, lines: &[String], runs: usize) -> Bench {
+    let mut result = Bench {
+        answer: String::new(),
+        times: vec![],
+        counters: vec![],
+    };
+    for _ in 0..runs.max(1) {
+        counters::take();
+        let start = Instant::now();
+        result.answer = solver(lines);
+        result.times.push(start.elapsed());
+        result.counters = counters::take();
+    }
+    result
+}
+
+pub fn render_counters(counters: &[(&str, u64)]) -> Vec<String> {
+    counters
+        .iter()
+        .map(|(name, value)| format!("  {}: {}", name, value))
+        .collect()
+}
+
+pub fn render(part: usize, bench: &Bench) -> Vec<String> {
+    let min = bench.times.iter().min().copied().unwrap_or_default();
+    let total: Duration = bench.times.iter().sum();
+    let mean = total / bench.times.len().max(1) as u32;
+    let mut lines = vec![
+        format!("part {}: {}", part, one_line(&bench.answer)),
+        format!(
+            "  time: min {:?}, mean {:?} over {} runs",
+            min,
+            mean,
+            bench.times.len()
+        ),
+    ];
+    lines.extend(render_counters(&bench.counters));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench() {
+        let solver: Solver = |lines| {
+            counters::add("lines", lines.len() as u64);
+            lines.len().to_string()
+        };
+        let lines = vec!["a".to_string(), "b".to_string()];
+        let result = bench(solver, &lines, 3);
+        assert_eq!(result.answer, "2");
+        assert_eq!(result.times.len(), 3);
+        assert_eq!(result.counters, vec![("lines", 2)]);
+    }
+
+    #[test]
+    fn test_render() {
+        let result = Bench {
+            answer: "##..\n..##".to_string(),
+            times: vec![Duration::from_millis(3), Duration::from_millis(1)],
+            counters: vec![("walk calls", 42)],
+        };
+        assert_eq!(
+            render(2, &result),
+            vec![
+                "part 2: ##../..##",
+                "  time: min 1ms, mean 2ms over 2 runs",
+                "  walk calls: 42",
+            ]
+        );
+    }
+}
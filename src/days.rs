@@ -0,0 +1,66 @@
+pub type Solver = fn(&[String]) -> String;
+
+pub struct Day {
+    pub part1: Solver,
+    pub part2: Option<Solver>,
+}
+
+impl Day {
+    pub fn solvers(&self) -> Vec<Solver> {
+        let mut solvers = vec![self.part1];
+        solvers.extend(self.part2);
+        solvers
+    }
+}
+
+// the row day 15 part 1 asks about for real inputs (the example uses 10)
+const DAY15_ROW: i32 = 2_000_000;
+
+macro_rules! day {
+    ($krate:ident) => {
+        Day {
+            part1: |lines| $krate::part1::solve(lines).to_string(),
+            part2: Some(|lines| $krate::part2::solve(lines).to_string()),
+        }
+    };
+}
+
+pub fn day(n: u32) -> Option<Day> {
+    let day = match n {
+        1 => day!(day01),
+        2 => day!(day02),
+        3 => day!(day03),
+        4 => day!(day04),
+        5 => day!(day05),
+        6 => day!(day06),
+        7 => day!(day07),
+        8 => day!(day08),
+        9 => day!(day09),
+        10 => Day {
+            part1: |lines| day10::part1::solve(lines).to_string(),
+            part2: Some(|lines| day10::part2::solve(lines).join("\n")),
+        },
+        11 => day!(day11),
+        12 => day!(day12),
+        13 => day!(day13),
+        14 => day!(day14),
+        15 => Day {
+            part1: |lines| day15::part1::solve(lines, DAY15_ROW).to_string(),
+            part2: Some(|lines| day15::part2::solve(lines, DAY15_ROW).to_string()),
+        },
+        16 => day!(day16),
+        18 => day!(day18),
+        19 => day!(day19),
+        20 => day!(day20),
+        21 => day!(day21),
+        22 => day!(day22),
+        23 => day!(day23),
+        24 => day!(day24),
+        25 => Day {
+            part1: |lines| day25::solve(lines),
+            part2: None,
+        },
+        _ => return None,
+    };
+    Some(day)
+}
@@ -1,123 +1,93 @@
-use std::io::{self, BufRead};
-
-fn parse_input(lines: &[String]) -> Vec<String> {
-    lines.to_vec()
+use std::{
+    env,
+    io::{self, BufRead},
+    path::Path,
+    process,
+};
+
+mod batch;
+mod bench;
+mod days;
+
+use batch::Check;
+use common::counters;
+
+fn usage() -> ! {
+    eprintln!("usage: aoc run <day> < input.txt");
+    eprintln!("       aoc batch <day> <dir>");
+    eprintln!("       aoc bench <day> [runs] < input.txt");
+    process::exit(2);
 }
 
-fn snafu_to_i64(s: &str) -> i64 {
-    let mut power = 1;
-    let mut sum = 0;
-    for c in s.chars().rev() {
-        match c {
-            '=' => sum += -2 * power,
-            '-' => sum -= power,
-            '0' => sum += 0,
-            '1' => sum += power,
-            '2' => sum += 2 * power,
-            _ => panic!(),
+fn get_day(day: &str) -> days::Day {
+    match day.parse().ok().and_then(days::day) {
+        Some(day) => day,
+        None => {
+            eprintln!("no solution for day {}", day);
+            process::exit(2);
         }
-        power *= 5;
     }
-    sum
 }
 
-fn i64_to_snafu(n: i64) -> String {
-    let mut d = 0;
-    loop {
-        let p = 5i64.pow(d);
-        let offset = (p - 1) / 2;
-        let bucket = (n + offset) / p;
-        if bucket.abs() <= 2 {
-            break;
-        }
-        d += 1;
-    }
-    // println!("now backwards from d={}", d);
-    let mut n = n;
-    let mut r = "".to_owned();
-    loop {
-        let p = 5i64.pow(d);
-        let offset = (p - 1) / 2;
-        let bucket = num::integer::div_floor(n + offset, p);
-        n -= bucket * p;
-
-        r.push(match bucket {
-            -2 => '=',
-            -1 => '-',
-            0 => '0',
-            1 => '1',
-            2 => '2',
-            _ => panic!(),
-        });
+fn read_stdin() -> Vec<String> {
+    let lines = io::stdin().lock().lines();
+    lines.map(|line| line.unwrap()).collect()
+}
 
-        if d == 0 {
-            break;
+// answers go to stdout, counters to stderr so the answers can still be piped
+fn run(day: &days::Day) {
+    let lines = read_stdin();
+    for (i, solver) in day.solvers().iter().enumerate() {
+        counters::take();
+        println!("{}", solver(&lines));
+        let counters = counters::take();
+        if !counters.is_empty() {
+            eprintln!("part {} counters:", i + 1);
+            for line in bench::render_counters(&counters) {
+                eprintln!("{}", line);
+            }
         }
-        d -= 1;
     }
-    r
 }
 
-fn solution(lines: &[String]) -> String {
-    let mut sum = 0;
-    for line in lines {
-        let n = snafu_to_i64(line);
-        println!("{}", n);
-        sum += n;
+fn run_bench(day: &days::Day, runs: &str) {
+    let Ok(runs) = runs.parse() else { usage() };
+    let lines = read_stdin();
+    for (i, solver) in day.solvers().iter().enumerate() {
+        let result = bench::bench(*solver, &lines, runs);
+        for line in bench::render(i + 1, &result) {
+            println!("{}", line);
+        }
     }
-    println!("{}", sum);
-    i64_to_snafu(sum)
-}
-
-fn solve(lines: &[String]) -> String {
-    solution(&parse_input(lines))
 }
 
-fn main() {
-    let lines = io::stdin().lock().lines();
-    let lines: Vec<String> = lines.map(|line| line.unwrap()).collect();
-    println!("{}", solve(&lines));
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{fs::File, io::BufReader};
-
-    use super::*;
-
-    fn test_file(filename: &str, solution: &str) {
-        let reader = BufReader::new(File::open(filename).unwrap());
-
-        let lines: Vec<String> = reader.lines().map(|x| x.unwrap()).collect();
-        assert_eq!(solve(&lines), solution);
+fn run_batch(day: &days::Day, dir: &str) {
+    let rows = batch::batch(day, Path::new(dir)).unwrap_or_else(|e| {
+        eprintln!("{}: {}", dir, e);
+        process::exit(2);
+    });
+    for line in batch::render(&rows) {
+        println!("{}", line);
     }
-
-    #[test]
-    fn test_example() {
-        test_file("example.txt", "2=-1=0");
-    }
-
-    #[test]
-    fn test_input() {
-        test_file("input.txt", "2=10---0===-1--01-20");
-    }
-
-    #[test]
-    fn test_i64_to_snafu() {
-        assert_eq!(i64_to_snafu(15), "1=0");
-        assert_eq!(i64_to_snafu(20), "1-0");
-        assert_eq!(i64_to_snafu(2022), "1=11-2");
-        assert_eq!(i64_to_snafu(12345), "1-0---0");
-        assert_eq!(i64_to_snafu(314159265), "1121-1110-1=0");
+    let mismatches = rows
+        .iter()
+        .flat_map(|row| row.answers.iter())
+        .filter(|(_, check)| matches!(check, Check::Mismatch(_)))
+        .count();
+    if mismatches > 0 {
+        eprintln!("{} answer(s) differ from {}", mismatches, batch::REGISTRY);
+        process::exit(1);
     }
+}
 
-    #[test]
-    fn test_snafu_to_i64() {
-        assert_eq!(snafu_to_i64("1=-0-2"), 1747);
-        assert_eq!(snafu_to_i64("12111"), 906);
-        assert_eq!(snafu_to_i64("2=0="), 198);
-        assert_eq!(snafu_to_i64("21"), 11);
-        assert_eq!(snafu_to_i64("2=01"), 201);
-        assert_eq!(snafu_to_i64("111"), 31);
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|x| x.as_str()).collect();
+    match args[..] {
+        ["run", day] => run(&get_day(day)),
+        ["batch", day, dir] => run_batch(&get_day(day), dir),
+        ["bench", day] => run_bench(&get_day(day), "10"),
+        ["bench", day, runs] => run_bench(&get_day(day), runs),
+        _ => usage(),
     }
 }
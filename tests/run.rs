@@ -0,0 +1,45 @@
+use std::{
+    env, fs,
+    fs::File,
+    process::{Command, Output},
+};
+
+fn aoc(args: &[&str], input: Option<&str>) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command.args(args);
+    if let Some(input) = input {
+        command.stdin(File::open(input).unwrap());
+    }
+    command.output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+// nothing but the answers, so they can be piped
+#[test]
+fn test_run() {
+    let output = aoc(&["run", "25"], Some("day 25/example.txt"));
+    assert_eq!(stdout(&output), "2=-1=0\n");
+    let output = aoc(&["run", "21"], Some("day 21/example.txt"));
+    assert_eq!(stdout(&output), "152\n301\n");
+}
+
+#[test]
+fn test_batch() {
+    let dir = env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy("day 21/example.txt", dir.join("example.txt")).unwrap();
+    let output = aoc(&["batch", "21", dir.to_str().unwrap()], None);
+    fs::remove_dir_all(&dir).unwrap();
+    let stdout = stdout(&output);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("file"));
+    assert_eq!(
+        lines[1].split_whitespace().collect::<Vec<_>>(),
+        ["example.txt", "152", "301"]
+    );
+}